@@ -0,0 +1,107 @@
+use crate::db::database::connect as connect_mongo;
+use crate::db::indexes::create_indexes;
+use crate::errors::ServiceError;
+use crate::models::admin::entities::AdminRole;
+use crate::services::admin_token::AdminTokenService;
+
+const USAGE: &str = "Usage:
+  mcim-rust-api token create <name> [--role operator|admin]
+  mcim-rust-api token list
+  mcim-rust-api token revoke <name>
+  mcim-rust-api indexes create";
+
+/// 执行命令行子命令，返回进程退出码
+pub async fn run(args: &[String]) -> i32 {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    if !matches!(args.as_slice(), ["token", _, ..] | ["indexes", "create"]) {
+        eprintln!("{}", USAGE);
+        return 2;
+    }
+
+    let mongo_client = match connect_mongo().await {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to connect to MongoDB: {}", e);
+            return 1;
+        }
+    };
+
+    match args.as_slice() {
+        ["token", command, rest @ ..] => {
+            token_command(AdminTokenService::new(mongo_client), command, rest).await
+        }
+        _ => match create_indexes(&mongo_client).await {
+            Ok(created) => {
+                for name in created {
+                    println!("Ensured index {}", name);
+                }
+                0
+            }
+            Err(e) => {
+                eprintln!("Failed to create indexes: {}", e);
+                1
+            }
+        },
+    }
+}
+
+async fn token_command(service: AdminTokenService, command: &str, rest: &[&str]) -> i32 {
+    let result = match (command, rest) {
+        ("create", [name]) => create_token(&service, name, AdminRole::Operator).await,
+        ("create", [name, "--role", role]) => match AdminRole::parse(role) {
+            Some(role) => create_token(&service, name, role).await,
+            None => {
+                eprintln!("Unknown role {}, expected operator or admin", role);
+                return 2;
+            }
+        },
+        ("list", []) => list_tokens(&service).await,
+        ("revoke", [name]) => service.revoke_token(name).await.map(|_| {
+            println!("Revoked token {}", name);
+        }),
+        _ => {
+            eprintln!("{}", USAGE);
+            return 2;
+        }
+    };
+
+    match result {
+        Ok(()) => 0,
+        Err(ServiceError::InvalidInput { reason, .. }) => {
+            eprintln!("{}", reason);
+            1
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            1
+        }
+    }
+}
+
+async fn create_token(
+    service: &AdminTokenService,
+    name: &str,
+    role: AdminRole,
+) -> Result<(), ServiceError> {
+    let (token, record) = service.create_token(name, role).await?;
+    println!("Created {:?} token {}", record.role, record.name);
+    println!("{}", token);
+    println!("The token is only shown once, store it securely.");
+    Ok(())
+}
+
+async fn list_tokens(service: &AdminTokenService) -> Result<(), ServiceError> {
+    for token in service.list_tokens().await? {
+        println!(
+            "{}\t{:?}\tcreated {}\tlast used {}",
+            token.name,
+            token.role,
+            token.created_at.to_rfc3339(),
+            token
+                .last_used_at
+                .map(|at| at.to_rfc3339())
+                .unwrap_or_else(|| "never".to_string())
+        );
+    }
+    Ok(())
+}
@@ -1,19 +1,108 @@
-use redis::{Client, aio::MultiplexedConnection};
+use prometheus::{IntGauge, Opts};
+use redis::{AsyncConnectionConfig, Client, aio::MultiplexedConnection};
 use std::env;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::Duration;
 
-pub async fn connect()
--> Result<Arc<MultiplexedConnection>, Box<dyn std::error::Error + Send + Sync>> {
-    let redis_url = env::var("REDIS_URL").unwrap_or_else(|_| "redis://localhost:6379".to_string());
+// Redis 不可用时请求应尽快失败，而不是挂起
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(1);
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(1);
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
 
-    let client = Client::open(redis_url)?;
+pub static REDIS_CONNECTED: LazyLock<IntGauge> = LazyLock::new(|| {
+    IntGauge::with_opts(
+        Opts::new("redis_connected", "Whether Redis is currently connected").namespace("api"),
+    )
+    .unwrap()
+});
 
-    // 获取异步 MultiplexedConnection
-    let conn = client.get_multiplexed_async_connection().await?;
+/// Redis 连接，断开时由后台任务重连
+///
+/// Redis 只用于缓存和同步队列，不可用时 `get` 返回 `None`，调用方应跳过相关逻辑
+#[derive(Clone)]
+pub struct RedisPool {
+    client: Client,
+    conn: Arc<RwLock<Option<MultiplexedConnection>>>,
+}
+
+impl RedisPool {
+    /// 尝试建立连接，失败时返回未连接的 pool
+    pub async fn connect_with(client: Client) -> Self {
+        let pool = Self {
+            client,
+            conn: Arc::new(RwLock::new(None)),
+        };
+        pool.reconnect().await;
+        pool
+    }
+
+    pub fn get(&self) -> Option<MultiplexedConnection> {
+        self.conn.read().unwrap().clone()
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.conn.read().unwrap().is_some()
+    }
+
+    async fn reconnect(&self) -> bool {
+        let config = AsyncConnectionConfig::new()
+            .set_connection_timeout(CONNECTION_TIMEOUT)
+            .set_response_timeout(RESPONSE_TIMEOUT);
+
+        let conn = match self
+            .client
+            .get_multiplexed_async_connection_with_config(&config)
+            .await
+        {
+            Ok(conn) => Some(conn),
+            Err(e) => {
+                log::warn!("Failed to connect to Redis: {}", e);
+                None
+            }
+        };
+
+        let connected = conn.is_some();
+        *self.conn.write().unwrap() = conn;
+        REDIS_CONNECTED.set(connected as i64);
+        connected
+    }
+
+    // 定期 PING，失败时标记为未连接并重连
+    async fn check(&self) {
+        if let Some(mut conn) = self.get() {
+            match redis::cmd("PING").query_async::<String>(&mut conn).await {
+                Ok(_) => return,
+                Err(e) => log::warn!("Redis health check failed: {}", e),
+            }
+        }
+
+        if self.reconnect().await {
+            log::info!("Reconnected to Redis");
+        }
+    }
+
+    pub fn spawn_health_check(&self) {
+        let pool = self.clone();
+        actix_web::rt::spawn(async move {
+            loop {
+                actix_web::rt::time::sleep(HEALTH_CHECK_INTERVAL).await;
+                pool.check().await;
+            }
+        });
+    }
+}
+
+pub async fn connect() -> Result<RedisPool, Box<dyn std::error::Error + Send + Sync>> {
+    let client = Client::open(get_redis_url())?;
+    let pool = RedisPool::connect_with(client).await;
 
-    log::info!("Connected to Redis successfully!");
+    if pool.is_connected() {
+        log::info!("Connected to Redis successfully!");
+    } else {
+        log::warn!("Redis is unavailable, running without cache and sync queue");
+    }
 
-    Ok(Arc::new(conn))
+    Ok(pool)
 }
 
 pub fn get_redis_url() -> String {
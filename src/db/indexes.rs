@@ -0,0 +1,80 @@
+use bson::{Document, doc};
+use mongodb::error::ErrorKind;
+use mongodb::options::IndexOptions;
+use mongodb::{Client, IndexModel};
+
+use crate::db::database::get_database_name;
+
+// 相同 keys 的索引已以其他名称存在
+const INDEX_OPTIONS_CONFLICT: i32 = 85;
+
+/// 接口依赖的索引：(集合, 索引名, keys, 是否唯一)
+///
+/// 本地搜索使用 `$text`，每个集合只能有一个文本索引
+fn required_indexes() -> Vec<(&'static str, &'static str, Document, bool)> {
+    vec![
+        // token 名称用于吊销，重复时只能吊销其中一个
+        ("admin_tokens", "name_unique", doc! { "name": 1 }, true),
+        (
+            "curseforge_files",
+            "modules_fingerprint",
+            doc! { "modules.fingerprint": 1 },
+            false,
+        ),
+        ("curseforge_files", "sha1", doc! { "sha1": 1 }, false),
+        ("curseforge_files", "mod_id", doc! { "modId": 1 }, false),
+        (
+            "curseforge_mods",
+            "search_text",
+            doc! { "name": "text", "slug": "text", "summary": "text" },
+            false,
+        ),
+        ("modrinth_files", "sha1", doc! { "_id.sha1": 1 }, false),
+        (
+            "modrinth_files",
+            "project_id",
+            doc! { "project_id": 1 },
+            false,
+        ),
+        (
+            "modrinth_projects",
+            "search_text",
+            doc! { "title": "text", "slug": "text", "description": "text" },
+            false,
+        ),
+    ]
+}
+
+/// 创建缺少的索引，已存在的同名或同 keys 索引不受影响，返回创建的索引名
+pub async fn create_indexes(client: &Client) -> Result<Vec<String>, mongodb::error::Error> {
+    let db = client.database(get_database_name().as_str());
+    let mut created = Vec::new();
+    for (collection, name, keys, unique) in required_indexes() {
+        let model = IndexModel::builder()
+            .keys(keys)
+            .options(
+                IndexOptions::builder()
+                    .name(name.to_string())
+                    .unique(unique)
+                    .build(),
+            )
+            .build();
+        match db
+            .collection::<Document>(collection)
+            .create_index(model)
+            .await
+        {
+            Ok(_) => created.push(format!("{}.{}", collection, name)),
+            // 已有相同 keys 但名称不同的索引，例如由 mcim-sync 创建
+            Err(e) if is_index_options_conflict(&e) => {
+                log::info!("Index {}.{} already exists: {}", collection, name, e)
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(created)
+}
+
+fn is_index_options_conflict(e: &mongodb::error::Error) -> bool {
+    matches!(e.kind.as_ref(), ErrorKind::Command(c) if c.code == INDEX_OPTIONS_CONFLICT)
+}
@@ -1,2 +1,4 @@
 pub mod _redis;
 pub mod database;
+pub mod indexes;
+pub mod sync_queue;
@@ -0,0 +1,301 @@
+use prometheus::{IntCounterVec, Opts};
+use redis::aio::MultiplexedConnection;
+use redis::{AsyncCommands, RedisResult, ToRedisArgs};
+use std::cell::{Cell, RefCell};
+use std::env;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::LazyLock;
+use std::task::{Context, Poll};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::db::_redis::RedisPool;
+
+// 同步队列，由 mcim-sync 消费
+pub const CURSEFORGE_MODIDS: &str = "curseforge_modids";
+pub const CURSEFORGE_FILEIDS: &str = "curseforge_fileids";
+pub const CURSEFORGE_FINGERPRINTS: &str = "curseforge_fingerprints";
+pub const MODRINTH_PROJECT_IDS: &str = "modrinth_project_ids";
+pub const MODRINTH_VERSION_IDS: &str = "modrinth_version_ids";
+pub const MODRINTH_TEAM_IDS: &str = "modrinth_team_ids";
+pub const MODRINTH_USER_IDS: &str = "modrinth_user_ids";
+pub const MODRINTH_HASHES_SHA1: &str = "modrinth_hashes_sha1";
+pub const MODRINTH_HASHES_SHA512: &str = "modrinth_hashes_sha512";
+
+pub const QUEUES: [&str; 9] = [
+    CURSEFORGE_MODIDS,
+    CURSEFORGE_FILEIDS,
+    CURSEFORGE_FINGERPRINTS,
+    MODRINTH_PROJECT_IDS,
+    MODRINTH_VERSION_IDS,
+    MODRINTH_TEAM_IDS,
+    MODRINTH_USER_IDS,
+    MODRINTH_HASHES_SHA1,
+    MODRINTH_HASHES_SHA512,
+];
+
+pub static QUEUE_DROPPED: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    IntCounterVec::new(
+        Opts::new(
+            "redis_queue_dropped",
+            "Number of queue members dropped because Redis was unavailable",
+        )
+        .namespace("api"),
+        &["queue"],
+    )
+    .unwrap()
+});
+
+// mcim-sync 迁移到新队列前，同时写入旧的 `{queue}` set
+pub static LEGACY_SETS_ENABLED: LazyLock<bool> = LazyLock::new(|| {
+    env::var("SYNC_QUEUE_LEGACY_SETS")
+        .unwrap_or_else(|_| "true".to_string())
+        .parse::<bool>()
+        .unwrap_or(true)
+});
+
+/// 队列在 Redis 中的 key
+///
+/// - `sync_queue:{queue}`：zset，score 为请求次数 hit_count
+/// - `sync_queue:{queue}:first_seen`：zset，score 为首次请求的 unix 时间戳
+/// - `sync_queue:{queue}:last_seen`：zset，score 为最近一次请求的 unix 时间戳
+/// - `sync_queue:{queue}:source`：hash，最近一次请求来源的接口
+/// - `{queue}`：旧版 set，仅在 `SYNC_QUEUE_LEGACY_SETS` 启用时写入
+pub struct QueueKeys {
+    pub hits: String,
+    pub first_seen: String,
+    pub last_seen: String,
+    pub source: String,
+    pub legacy: Option<String>,
+}
+
+impl QueueKeys {
+    pub fn new(queue: &str) -> Self {
+        let hits = format!("sync_queue:{}", queue);
+        Self {
+            first_seen: format!("{}:first_seen", hits),
+            last_seen: format!("{}:last_seen", hits),
+            source: format!("{}:source", hits),
+            hits,
+            legacy: LEGACY_SETS_ENABLED.then(|| queue.to_string()),
+        }
+    }
+}
+
+pub(crate) fn unix_timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+thread_local! {
+    // 当前正在 poll 的 `track_enqueue` 标记，actix 的 worker 为单线程
+    static ENQUEUE_TRACKER: RefCell<Option<Rc<Cell<bool>>>> = const { RefCell::new(None) };
+}
+
+struct TrackEnqueue<F> {
+    future: Pin<Box<F>>,
+    flag: Rc<Cell<bool>>,
+}
+
+impl<F: Future> Future for TrackEnqueue<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let previous = ENQUEUE_TRACKER.with(|t| t.replace(Some(this.flag.clone())));
+        let poll = this.future.as_mut().poll(cx);
+        ENQUEUE_TRACKER.with(|t| t.replace(previous));
+        poll
+    }
+}
+
+/// 执行 `future` 并返回期间是否调用过 `enqueue`
+///
+/// 只记录在 `future` 内直接 await 的调用，`spawn` 出去的任务不计入
+pub async fn track_enqueue<F: Future>(future: F) -> (F::Output, bool) {
+    let flag = Rc::new(Cell::new(false));
+    let output = TrackEnqueue {
+        future: Box::pin(future),
+        flag: flag.clone(),
+    }
+    .await;
+    (output, flag.get())
+}
+
+/// 将未命中的 ID 写入同步队列，Redis 不可用时丢弃并计数
+pub async fn enqueue<V>(redis: &RedisPool, queue: &str, members: &[V], source: &str)
+where
+    V: ToRedisArgs + Send + Sync + std::fmt::Debug,
+{
+    if members.is_empty() {
+        return;
+    }
+
+    ENQUEUE_TRACKER.with(|t| {
+        if let Some(flag) = t.borrow().as_ref() {
+            flag.set(true);
+        }
+    });
+
+    let Some(mut conn) = redis.get() else {
+        QUEUE_DROPPED
+            .with_label_values(&[queue])
+            .inc_by(members.len() as u64);
+        return;
+    };
+
+    match push_members(&mut conn, queue, members, source).await {
+        Ok(()) => log::debug!("Added {:?} to sync queue {}", members, queue),
+        Err(e) => {
+            log::warn!("Failed to add members to sync queue {}: {}", queue, e);
+            QUEUE_DROPPED
+                .with_label_values(&[queue])
+                .inc_by(members.len() as u64);
+        }
+    }
+}
+
+/// 写入同步队列并返回 Redis 错误，供需要确认写入结果的调用方使用
+pub async fn push_members<V>(
+    conn: &mut MultiplexedConnection,
+    queue: &str,
+    members: &[V],
+    source: &str,
+) -> RedisResult<()>
+where
+    V: ToRedisArgs + Send + Sync,
+{
+    if members.is_empty() {
+        return Ok(());
+    }
+
+    let keys = QueueKeys::new(queue);
+    let now = unix_timestamp();
+    let timestamps: Vec<(i64, &V)> = members.iter().map(|member| (now, member)).collect();
+    let sources: Vec<(&V, &str)> = members.iter().map(|member| (member, source)).collect();
+
+    let mut pipe = redis::pipe();
+    pipe.atomic();
+    for member in members {
+        pipe.zincr(&keys.hits, member, 1).ignore();
+    }
+    pipe.cmd("ZADD")
+        .arg(&keys.first_seen)
+        .arg("NX")
+        .arg(&timestamps)
+        .ignore()
+        .zadd_multiple(&keys.last_seen, &timestamps)
+        .ignore()
+        .hset_multiple(&keys.source, &sources)
+        .ignore();
+    if let Some(legacy) = &keys.legacy {
+        pipe.sadd(legacy, members).ignore();
+    }
+
+    pipe.query_async::<()>(conn).await
+}
+
+/// 队列长度，以及最近 `window` 秒内新增和被请求的成员数
+pub async fn queue_stats(
+    conn: &mut MultiplexedConnection,
+    queue: &str,
+    window: i64,
+) -> RedisResult<(u64, u64, u64)> {
+    let keys = QueueKeys::new(queue);
+    let since = unix_timestamp() - window;
+
+    redis::pipe()
+        .zcard(&keys.hits)
+        .zcount(&keys.first_seen, since, "+inf")
+        .zcount(&keys.last_seen, since, "+inf")
+        .query_async(conn)
+        .await
+}
+
+type Scores = Vec<Option<i64>>;
+
+/// 成员及其元数据：(member, hit_count, first_seen, last_seen, source)
+pub type QueueEntry = (String, i64, Option<i64>, Option<i64>, Option<String>);
+
+/// 按请求次数降序取出部分成员
+pub async fn queue_entries(
+    conn: &mut MultiplexedConnection,
+    queue: &str,
+    offset: isize,
+    limit: isize,
+) -> RedisResult<Vec<QueueEntry>> {
+    let keys = QueueKeys::new(queue);
+    let ranked: Vec<(String, i64)> = conn
+        .zrevrange_withscores(&keys.hits, offset, offset + limit - 1)
+        .await?;
+    if ranked.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let members: Vec<&str> = ranked.iter().map(|(member, _)| member.as_str()).collect();
+    let (first_seen, last_seen, sources): (Scores, Scores, Vec<Option<String>>) = redis::pipe()
+        .cmd("ZMSCORE")
+        .arg(&keys.first_seen)
+        .arg(&members)
+        .cmd("ZMSCORE")
+        .arg(&keys.last_seen)
+        .arg(&members)
+        .cmd("HMGET")
+        .arg(&keys.source)
+        .arg(&members)
+        .query_async(conn)
+        .await?;
+
+    Ok(ranked
+        .into_iter()
+        .zip(first_seen)
+        .zip(last_seen)
+        .zip(sources)
+        .map(|((((member, hits), first), last), source)| (member, hits, first, last, source))
+        .collect())
+}
+
+/// 从队列中移除成员，返回实际移除的数量
+pub async fn remove_members(
+    conn: &mut MultiplexedConnection,
+    queue: &str,
+    members: &[String],
+) -> RedisResult<u64> {
+    if members.is_empty() {
+        return Ok(0);
+    }
+
+    let keys = QueueKeys::new(queue);
+    let mut pipe = redis::pipe();
+    pipe.atomic()
+        .zrem(&keys.hits, members)
+        .zrem(&keys.first_seen, members)
+        .ignore()
+        .zrem(&keys.last_seen, members)
+        .ignore()
+        .hdel(&keys.source, members)
+        .ignore();
+    if let Some(legacy) = &keys.legacy {
+        pipe.srem(legacy, members).ignore();
+    }
+    let (removed,): (u64,) = pipe.query_async(conn).await?;
+    Ok(removed)
+}
+
+/// 清空队列，返回清空前的长度
+pub async fn clear_queue(conn: &mut MultiplexedConnection, queue: &str) -> RedisResult<u64> {
+    let keys = QueueKeys::new(queue);
+    let mut pipe = redis::pipe();
+    pipe.atomic()
+        .zcard(&keys.hits)
+        .del(&[&keys.hits, &keys.first_seen, &keys.last_seen, &keys.source])
+        .ignore();
+    if let Some(legacy) = &keys.legacy {
+        pipe.del(legacy).ignore();
+    }
+    let (size,): (u64,) = pipe.query_async(conn).await?;
+    Ok(size)
+}
@@ -5,10 +5,12 @@ use std::fmt::Display;
 use crate::errors::ServiceError;
 use crate::models::ErrorResponse;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ApiError {
     NotFound(String),
     BadRequest(String),
+    Unauthorized(String),
+    Forbidden(String),
     InternalServerError(String),
 }
 
@@ -25,6 +27,16 @@ impl ApiError {
                 error: "Bad Request".to_string(),
                 detail: Some(msg.clone()),
             },
+            ApiError::Unauthorized(msg) => ErrorResponse {
+                code: 401,
+                error: "Unauthorized".to_string(),
+                detail: Some(msg.clone()),
+            },
+            ApiError::Forbidden(msg) => ErrorResponse {
+                code: 403,
+                error: "Forbidden".to_string(),
+                detail: Some(msg.clone()),
+            },
             ApiError::InternalServerError(msg) => ErrorResponse {
                 code: 500,
                 error: "Internal Server Error".to_string(),
@@ -66,6 +78,8 @@ impl Display for ApiError {
         match self {
             ApiError::NotFound(msg) => write!(f, "Not Found: {}", msg),
             ApiError::BadRequest(msg) => write!(f, "Bad Request: {}", msg),
+            ApiError::Unauthorized(msg) => write!(f, "Unauthorized: {}", msg),
+            ApiError::Forbidden(msg) => write!(f, "Forbidden: {}", msg),
             ApiError::InternalServerError(msg) => write!(f, "Internal Server Error: {}", msg),
         }
     }
@@ -76,6 +90,8 @@ impl StdError for ApiError {
         match self {
             ApiError::NotFound(_) => None,
             ApiError::BadRequest(_) => None,
+            ApiError::Unauthorized(_) => None,
+            ApiError::Forbidden(_) => None,
             ApiError::InternalServerError(_) => None,
         }
     }
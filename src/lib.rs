@@ -1,3 +1,4 @@
+pub mod cli;
 pub mod db;
 pub mod errors;
 pub mod models;
@@ -6,13 +7,13 @@ pub mod services;
 pub mod utils;
 
 pub mod test_utils {
+    use crate::db::_redis::RedisPool;
+    use crate::db::indexes::create_indexes;
     use crate::routes::config;
-    use crate::utils::app::build_app_state;
+    use crate::utils::app::{AppState, build_app_state};
     use actix_web::{App, web};
     use dotenvy::dotenv;
     use mongodb::{Client, options::ClientOptions};
-    use redis::aio::MultiplexedConnection;
-    use std::sync::Arc;
 
     pub async fn init_test_db() -> Client {
         let client_options = ClientOptions::parse("mongodb://localhost:27017")
@@ -22,17 +23,15 @@ pub mod test_utils {
         Client::with_options(client_options).expect("Failed to initialize MongoDB client")
     }
 
-    pub async fn init_test_redis() -> Arc<MultiplexedConnection> {
+    pub async fn init_test_redis() -> RedisPool {
         let client =
             redis::Client::open("redis://localhost:6379").expect("Failed to create Redis client");
 
-        let pool = client
-            .get_multiplexed_async_connection()
-            .await
-            .expect("Failed to connect to Redis");
+        let pool = RedisPool::connect_with(client).await;
+        assert!(pool.is_connected(), "Failed to connect to Redis");
 
         println!("Connected to Redis successfully!");
-        Arc::new(pool)
+        pool
     }
 
     pub async fn create_test_app() -> App<
@@ -43,14 +42,36 @@ pub mod test_utils {
             Error = actix_web::Error,
             InitError = (),
         >,
+    > {
+        create_test_app_with(|_| {}).await
+    }
+
+    // 允许测试在构建后修改 AppState，例如切换搜索模式
+    pub async fn create_test_app_with(
+        configure_state: impl FnOnce(&mut AppState),
+    ) -> App<
+        impl actix_web::dev::ServiceFactory<
+            actix_web::dev::ServiceRequest,
+            Config = (),
+            Response = actix_web::dev::ServiceResponse,
+            Error = actix_web::Error,
+            InitError = (),
+        >,
     > {
         dotenv().ok();
 
         let mongo_client = init_test_db().await;
+        // 本地搜索依赖文本索引
+        create_indexes(&mongo_client)
+            .await
+            .expect("Failed to create MongoDB indexes");
         let redis_client = init_test_redis().await;
 
+        let mut app_state = build_app_state(mongo_client, redis_client);
+        configure_state(&mut app_state);
+
         App::new()
-            .app_data(web::Data::new(build_app_state(mongo_client, redis_client)))
+            .app_data(web::Data::new(app_state))
             .configure(config)
     }
 }
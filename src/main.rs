@@ -1,3 +1,4 @@
+pub mod cli;
 pub mod db;
 pub mod errors;
 pub mod models;
@@ -7,18 +8,22 @@ pub mod utils;
 
 use actix_middleware_etag::Etag;
 use actix_web::dev::Service;
-use actix_web::middleware::{Compress, Logger};
+use actix_web::middleware::{Compress, Logger, from_fn};
 use actix_web::{App, HttpServer, dev::ServiceRequest, web};
 use actix_web_prom::PrometheusMetricsBuilder;
 use dotenvy::dotenv;
 use prometheus::{IntCounterVec, Opts};
 use std::env;
 
-use crate::db::_redis::connect as connect_redis;
+use crate::db::_redis::{REDIS_CONNECTED, connect as connect_redis};
 use crate::db::database::connect as connect_mongo;
+use crate::db::sync_queue::QUEUE_DROPPED;
 use crate::errors::ApiError;
 use crate::routes::config as routes_config;
 use crate::utils::app::build_app_state;
+use crate::utils::user_agent_filter::{
+    USER_AGENT_REJECTED, USER_AGENT_RULE_HITS, filter_user_agent,
+};
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
@@ -28,15 +33,31 @@ async fn main() -> std::io::Result<()> {
     // 初始化日志记录
     env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
 
+    // 命令行子命令，例如 token 管理
+    let args: Vec<String> = env::args().skip(1).collect();
+    if !args.is_empty() {
+        std::process::exit(crate::cli::run(&args).await);
+    }
+
     // 配置MongoDB连接
     let mongo_client = connect_mongo().await.expect("Failed to connect to MongoDB");
-    let redis_pool = connect_redis().await.expect("Failed to connect to Redis");
+    // Redis 不可用时仍然启动，由后台任务重连
+    let redis_pool = connect_redis().await.expect("Invalid Redis URL");
+    redis_pool.spawn_health_check();
 
     // 获取服务器端口，默认为 8080
     let port = env::var("PORT").unwrap_or_else(|_| "8080".to_string());
     let bind_address = format!("0.0.0.0:{}", port);
 
     let app_state = build_app_state(mongo_client, redis_pool);
+
+    // UA 过滤启用时先加载规则，之后由后台任务定期重新加载
+    if app_state.user_agent_filter.is_enabled() {
+        app_state.user_agent_filter.reload(&app_state.db).await;
+        app_state
+            .user_agent_filter
+            .spawn_reload(app_state.db.clone());
+    }
     let app_data = web::Data::new(app_state);
 
     // Prometheus Metrics 初始化
@@ -60,6 +81,23 @@ async fn main() -> std::io::Result<()> {
         .register(Box::new(user_agent_counter.clone()))
         .unwrap();
 
+    prometheus
+        .registry
+        .register(Box::new(REDIS_CONNECTED.clone()))
+        .unwrap();
+    prometheus
+        .registry
+        .register(Box::new(QUEUE_DROPPED.clone()))
+        .unwrap();
+    prometheus
+        .registry
+        .register(Box::new(USER_AGENT_RULE_HITS.clone()))
+        .unwrap();
+    prometheus
+        .registry
+        .register(Box::new(USER_AGENT_REJECTED.clone()))
+        .unwrap();
+
     // 将 counter 存入 AppData 供中间件使用
     let user_agent_counter_data = web::Data::new(user_agent_counter);
 
@@ -89,6 +127,7 @@ async fn main() -> std::io::Result<()> {
                     .error_handler(|err, _| ApiError::BadRequest(err.to_string()).into()),
             )
             .app_data(user_agent_counter_data.clone())
+            .wrap(from_fn(filter_user_agent))
             .wrap_fn(|req, srv| {
                 // 提取 User-Agent
                 let user_agent = req
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use utoipa::ToSchema;
+
+use bson::serde_helpers::datetime::FromChrono04DateTime;
+
+/// operator 只能调用只读接口，admin 可以调用全部接口
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AdminRole {
+    Operator,
+    Admin,
+}
+
+impl AdminRole {
+    pub fn parse(role: &str) -> Option<Self> {
+        match role.to_lowercase().as_str() {
+            "operator" => Some(AdminRole::Operator),
+            "admin" => Some(AdminRole::Admin),
+            _ => None,
+        }
+    }
+}
+
+/// 只保存 token 的 sha256，明文仅在创建时输出一次
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AdminToken {
+    #[serde(rename = "_id")]
+    pub token_hash: String,
+    pub name: String,
+    pub role: AdminRole,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub created_at: DateTime<Utc>,
+
+    #[serde_as(as = "Option<FromChrono04DateTime>")]
+    #[serde(default)]
+    pub last_used_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct QueueEntriesQuery {
+    pub offset: Option<isize>,
+    pub limit: Option<isize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct QueueMembersBody {
+    /// 数字 ID 也以字符串形式传入
+    pub members: Vec<String>,
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct QueueSummary {
+    pub queue: String,
+    pub size: u64,
+    /// 最近一小时首次进入队列的成员数
+    pub enqueued_last_hour: u64,
+    /// 最近一小时被请求过的成员数
+    pub requested_last_hour: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct QueueEntry {
+    pub member: String,
+    pub hit_count: i64,
+    /// unix 时间戳
+    pub first_seen: Option<i64>,
+    pub last_seen: Option<i64>,
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct QueueDetail {
+    #[serde(flatten)]
+    pub summary: QueueSummary,
+    pub offset: isize,
+    pub limit: isize,
+    /// 按 hit_count 降序排列
+    pub entries: Vec<QueueEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct QueueMutationResponse {
+    pub queue: String,
+    /// 实际移除、重新入队或清空的成员数
+    pub affected: u64,
+}
@@ -337,3 +337,184 @@ pub struct FileIndex {
     #[serde(rename = "modLoader")]
     pub mod_loader: Option<i32>,
 }
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct GameAssets {
+    #[serde(rename = "iconUrl")]
+    pub icon_url: Option<String>,
+    #[serde(rename = "tileUrl")]
+    pub tile_url: Option<String>,
+    #[serde(rename = "coverUrl")]
+    pub cover_url: Option<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct Game {
+    #[serde(alias = "_id")]
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+    #[serde(rename = "dateModified")]
+    #[serde_as(as = "Option<FromChrono04DateTime>")]
+    pub date_modified: Option<DateTime<Utc>>,
+    pub assets: Option<GameAssets>,
+    pub status: Option<i32>,
+    #[serde(rename = "apiStatus")]
+    pub api_status: Option<i32>,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct GameVersion {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+}
+
+// 按 gameVersionTypeId 分组存储，_id 即为 type
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct GameVersionsByType {
+    #[serde(rename = "type", alias = "_id")]
+    pub version_type: i32,
+    #[serde(rename = "gameId")]
+    pub game_id: i32,
+    pub versions: Vec<GameVersion>,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct GameVersionType {
+    #[serde(alias = "_id")]
+    pub id: i32,
+    #[serde(rename = "gameId")]
+    pub game_id: i32,
+    pub name: String,
+    pub slug: String,
+    #[serde(rename = "isSyncable")]
+    pub is_syncable: Option<bool>,
+    pub status: Option<i32>,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+// HTML 内容单独存放，避免 curseforge_mods / curseforge_files 文档过大
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct ModDescription {
+    #[serde(alias = "_id")]
+    pub id: i32,
+    pub description: String,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct FileChangelog {
+    #[serde(alias = "_id")]
+    pub id: i32,
+    #[serde(rename = "modId")]
+    pub mod_id: i32,
+    pub changelog: String,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct MinecraftGameVersion {
+    #[serde(alias = "_id")]
+    pub id: i32,
+    #[serde(rename = "gameVersionId")]
+    pub game_version_id: i32,
+    #[serde(rename = "versionString")]
+    pub version_string: String,
+    #[serde(rename = "jarDownloadUrl")]
+    pub jar_download_url: Option<String>,
+    #[serde(rename = "jsonDownloadUrl")]
+    pub json_download_url: Option<String>,
+    pub approved: Option<bool>,
+    #[serde(rename = "dateModified")]
+    #[serde_as(as = "Option<FromChrono04DateTime>")]
+    pub date_modified: Option<DateTime<Utc>>,
+    #[serde(rename = "gameVersionTypeId")]
+    pub game_version_type_id: Option<i32>,
+    #[serde(rename = "gameVersionStatus")]
+    pub game_version_status: Option<i32>,
+    #[serde(rename = "gameVersionTypeStatus")]
+    pub game_version_type_status: Option<i32>,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+// modloader 列表 (MinecraftModLoaderIndex) 由完整文档投影得到，不单独存储
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct MinecraftModLoaderVersion {
+    #[serde(alias = "_id")]
+    pub id: i32,
+    #[serde(rename = "gameVersionId")]
+    pub game_version_id: Option<i32>,
+    #[serde(rename = "minecraftGameVersionId")]
+    pub minecraft_game_version_id: Option<i32>,
+    #[serde(rename = "forgeVersion")]
+    pub forge_version: Option<String>,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub loader_type: Option<i32>,
+    #[serde(rename = "downloadUrl")]
+    pub download_url: Option<String>,
+    pub filename: Option<String>,
+    #[serde(rename = "installMethod")]
+    pub install_method: Option<i32>,
+    pub latest: Option<bool>,
+    pub recommended: Option<bool>,
+    pub approved: Option<bool>,
+    #[serde(rename = "dateModified")]
+    #[serde_as(as = "Option<FromChrono04DateTime>")]
+    pub date_modified: Option<DateTime<Utc>>,
+    #[serde(rename = "mavenVersionString")]
+    pub maven_version_string: Option<String>,
+    #[serde(rename = "versionJson")]
+    pub version_json: Option<String>,
+    #[serde(rename = "librariesInstallLocation")]
+    pub libraries_install_location: Option<String>,
+    #[serde(rename = "minecraftVersion")]
+    pub minecraft_version: Option<String>,
+    #[serde(rename = "additionalFilesJson")]
+    pub additional_files_json: Option<String>,
+    #[serde(rename = "modLoaderGameVersionId")]
+    pub mod_loader_game_version_id: Option<i32>,
+    #[serde(rename = "modLoaderGameVersionTypeId")]
+    pub mod_loader_game_version_type_id: Option<i32>,
+    #[serde(rename = "modLoaderGameVersionStatus")]
+    pub mod_loader_game_version_status: Option<i32>,
+    #[serde(rename = "modLoaderGameVersionTypeStatus")]
+    pub mod_loader_game_version_type_status: Option<i32>,
+    #[serde(rename = "mcGameVersionId")]
+    pub mc_game_version_id: Option<i32>,
+    #[serde(rename = "mcGameVersionTypeId")]
+    pub mc_game_version_type_id: Option<i32>,
+    #[serde(rename = "mcGameVersionStatus")]
+    pub mc_game_version_status: Option<i32>,
+    #[serde(rename = "mcGameVersionTypeStatus")]
+    pub mc_game_version_type_status: Option<i32>,
+    #[serde(rename = "installProfileJson")]
+    pub install_profile_json: Option<String>,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
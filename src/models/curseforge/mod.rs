@@ -1,3 +1,7 @@
 pub mod entities;
 pub mod requests;
 pub mod responses;
+
+// Curseforge HashAlgo
+pub const HASH_ALGO_SHA1: i32 = 1;
+pub const HASH_ALGO_MD5: i32 = 2;
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[derive(Serialize, Deserialize, Debug, Default, ToSchema)]
 pub struct SearchQuery {
     #[serde(rename = "gameId")]
     pub game_id: Option<i32>,
@@ -69,7 +69,14 @@ pub struct ModsBody {
     #[serde(rename = "modIds")]
     #[schema(default = "[238222]")]
     pub mod_ids: Vec<i32>,
+    /// Only return mods that have files for a PC (Minecraft Java Edition) game version type,
+    /// judged by `latestFilesIndexes` and `latestFiles[].sortableGameVersions`.
+    /// Only the numbered `Minecraft <version>` types (e.g. `Minecraft 1.20`, `Minecraft 26.1`)
+    /// count as Java Edition; Modloader, Environment, Bedrock and other non-edition types are
+    /// ignored, so mods that only carry those are filtered out.
+    /// Defaults to false.
     #[serde(rename = "filterPcOnly")]
+    #[schema(default = false)]
     pub filter_pc_only: Option<bool>,
 }
 
@@ -79,6 +86,16 @@ pub struct ModFilesQuery {
     pub game_version: Option<String>,
     #[serde(rename = "modLoaderType")]
     pub mod_loader_type: Option<i32>,
+    #[serde(rename = "modLoaderTypes")]
+    pub mod_loader_types: Option<String>,
+    #[serde(rename = "gameVersionTypeId")]
+    pub game_version_type_id: Option<i32>,
+    #[serde(rename = "releaseType")]
+    pub release_type: Option<i32>,
+    #[serde(rename = "sortField")]
+    pub sort_field: Option<String>,
+    #[serde(rename = "sortOrder")]
+    pub sort_order: Option<String>,
     pub index: Option<i32>,
     #[serde(rename = "pageSize")]
     pub page_size: Option<i32>,
@@ -97,6 +114,21 @@ pub struct FingerprintsBody {
     pub fingerprints: Vec<i64>,
 }
 
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct FolderFingerprint {
+    pub foldername: String,
+    #[schema(default = "[3455112628]")]
+    pub fingerprints: Vec<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct FuzzyFingerprintsBody {
+    #[serde(rename = "gameId")]
+    #[schema(default = 432)]
+    pub game_id: Option<i32>,
+    pub fingerprints: Vec<FolderFingerprint>,
+}
+
 #[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct CategoriesQuery {
     #[serde(rename = "gameId")]
@@ -108,3 +140,23 @@ pub struct CategoriesQuery {
     #[serde(rename = "classesOnly")]
     pub classes_only: Option<bool>,
 }
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct GamesQuery {
+    pub index: Option<i32>,
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct MinecraftVersionsQuery {
+    #[serde(rename = "sortDescending")]
+    pub sort_descending: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct MinecraftModLoadersQuery {
+    pub version: Option<String>,
+    #[serde(rename = "includeAll")]
+    pub include_all: Option<bool>,
+}
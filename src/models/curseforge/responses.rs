@@ -33,6 +33,180 @@ pub struct Category {
 
 #[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 
+pub struct GameAssets {
+    #[serde(rename = "iconUrl")]
+    pub icon_url: Option<String>,
+    #[serde(rename = "tileUrl")]
+    pub tile_url: Option<String>,
+    #[serde(rename = "coverUrl")]
+    pub cover_url: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct Game {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+    #[serde(rename = "dateModified")]
+    pub date_modified: Option<DateTime<Utc>>,
+    pub assets: Option<GameAssets>,
+    pub status: Option<i32>,
+    #[serde(rename = "apiStatus")]
+    pub api_status: Option<i32>,
+
+    #[serde(default = "Utc::now")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct GameVersion {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+}
+
+// /v1/games/{gameId}/versions 只返回版本名
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct GameVersionsByType {
+    #[serde(rename = "type")]
+    pub version_type: i32,
+    pub versions: Vec<String>,
+
+    #[serde(default = "Utc::now")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct GameVersionsByTypeV2 {
+    #[serde(rename = "type")]
+    pub version_type: i32,
+    pub versions: Vec<GameVersion>,
+
+    #[serde(default = "Utc::now")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct GameVersionType {
+    pub id: i32,
+    #[serde(rename = "gameId")]
+    pub game_id: i32,
+    pub name: String,
+    pub slug: String,
+    #[serde(rename = "isSyncable")]
+    pub is_syncable: Option<bool>,
+    pub status: Option<i32>,
+
+    #[serde(default = "Utc::now")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct MinecraftGameVersion {
+    pub id: i32,
+    #[serde(rename = "gameVersionId")]
+    pub game_version_id: i32,
+    #[serde(rename = "versionString")]
+    pub version_string: String,
+    #[serde(rename = "jarDownloadUrl")]
+    pub jar_download_url: Option<String>,
+    #[serde(rename = "jsonDownloadUrl")]
+    pub json_download_url: Option<String>,
+    pub approved: Option<bool>,
+    #[serde(rename = "dateModified")]
+    pub date_modified: Option<DateTime<Utc>>,
+    #[serde(rename = "gameVersionTypeId")]
+    pub game_version_type_id: Option<i32>,
+    #[serde(rename = "gameVersionStatus")]
+    pub game_version_status: Option<i32>,
+    #[serde(rename = "gameVersionTypeStatus")]
+    pub game_version_type_status: Option<i32>,
+
+    #[serde(default = "Utc::now")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct MinecraftModLoaderIndex {
+    pub name: String,
+    #[serde(rename = "gameVersion")]
+    pub game_version: Option<String>,
+    pub latest: Option<bool>,
+    pub recommended: Option<bool>,
+    #[serde(rename = "dateModified")]
+    pub date_modified: Option<DateTime<Utc>>,
+    #[serde(rename = "type")]
+    pub loader_type: Option<i32>,
+
+    #[serde(default = "Utc::now")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct MinecraftModLoaderVersion {
+    pub id: i32,
+    #[serde(rename = "gameVersionId")]
+    pub game_version_id: Option<i32>,
+    #[serde(rename = "minecraftGameVersionId")]
+    pub minecraft_game_version_id: Option<i32>,
+    #[serde(rename = "forgeVersion")]
+    pub forge_version: Option<String>,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub loader_type: Option<i32>,
+    #[serde(rename = "downloadUrl")]
+    pub download_url: Option<String>,
+    pub filename: Option<String>,
+    #[serde(rename = "installMethod")]
+    pub install_method: Option<i32>,
+    pub latest: Option<bool>,
+    pub recommended: Option<bool>,
+    pub approved: Option<bool>,
+    #[serde(rename = "dateModified")]
+    pub date_modified: Option<DateTime<Utc>>,
+    #[serde(rename = "mavenVersionString")]
+    pub maven_version_string: Option<String>,
+    #[serde(rename = "versionJson")]
+    pub version_json: Option<String>,
+    #[serde(rename = "librariesInstallLocation")]
+    pub libraries_install_location: Option<String>,
+    #[serde(rename = "minecraftVersion")]
+    pub minecraft_version: Option<String>,
+    #[serde(rename = "additionalFilesJson")]
+    pub additional_files_json: Option<String>,
+    #[serde(rename = "modLoaderGameVersionId")]
+    pub mod_loader_game_version_id: Option<i32>,
+    #[serde(rename = "modLoaderGameVersionTypeId")]
+    pub mod_loader_game_version_type_id: Option<i32>,
+    #[serde(rename = "modLoaderGameVersionStatus")]
+    pub mod_loader_game_version_status: Option<i32>,
+    #[serde(rename = "modLoaderGameVersionTypeStatus")]
+    pub mod_loader_game_version_type_status: Option<i32>,
+    #[serde(rename = "mcGameVersionId")]
+    pub mc_game_version_id: Option<i32>,
+    #[serde(rename = "mcGameVersionTypeId")]
+    pub mc_game_version_type_id: Option<i32>,
+    #[serde(rename = "mcGameVersionStatus")]
+    pub mc_game_version_status: Option<i32>,
+    #[serde(rename = "mcGameVersionTypeStatus")]
+    pub mc_game_version_type_status: Option<i32>,
+    #[serde(rename = "installProfileJson")]
+    pub install_profile_json: Option<String>,
+
+    #[serde(default = "Utc::now")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
 pub struct CategoryInfo {
     pub id: Option<i32>,
     #[serde(rename = "gameId")]
@@ -346,6 +520,23 @@ pub struct FingerprintResult {
 
 #[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 
+pub struct FuzzyFingerprintMatch {
+    pub id: i32,
+    pub file: File,
+    #[serde(rename = "latestFiles")]
+    pub latest_files: Vec<FileInfo>,
+    pub fingerprints: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct FuzzyFingerprintResult {
+    #[serde(rename = "fuzzyMatches")]
+    pub fuzzy_matches: Vec<FuzzyFingerprintMatch>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
 pub struct SearchResponse {
     pub data: Vec<Mod>, // Changed from ModInfo
     pub pagination: Pagination,
@@ -371,6 +562,18 @@ pub struct DownloadUrlResponse {
 
 #[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 
+pub struct ModDescriptionResponse {
+    pub data: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct FileChangelogResponse {
+    pub data: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
 pub struct ModResponse {
     pub data: Mod,
 }
@@ -408,10 +611,71 @@ pub struct FingerprintResponse {
 
 #[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 
+pub struct FuzzyFingerprintResponse {
+    pub data: FuzzyFingerprintResult,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
 pub struct CategoriesResponse {
     pub data: Vec<Category>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct GameResponse {
+    pub data: Game,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct GamesResponse {
+    pub data: Vec<Game>,
+    pub pagination: Pagination,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct GameVersionsResponse {
+    pub data: Vec<GameVersionsByType>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct GameVersionsV2Response {
+    pub data: Vec<GameVersionsByTypeV2>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct GameVersionTypesResponse {
+    pub data: Vec<GameVersionType>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct MinecraftGameVersionsResponse {
+    pub data: Vec<MinecraftGameVersion>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct MinecraftGameVersionResponse {
+    pub data: MinecraftGameVersion,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct MinecraftModLoaderIndexResponse {
+    pub data: Vec<MinecraftModLoaderIndex>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+
+pub struct MinecraftModLoaderVersionResponse {
+    pub data: MinecraftModLoaderVersion,
+}
+
 // --- Conversions from DB Entity to Response Model ---
 
 // Macro to reduce boilerplate for simple struct conversions
@@ -704,6 +968,142 @@ impl From<db::Mod> for Mod {
     }
 }
 
+impl From<db::GameAssets> for GameAssets {
+    fn from(db_model: db::GameAssets) -> Self {
+        Self {
+            icon_url: db_model.icon_url,
+            tile_url: db_model.tile_url,
+            cover_url: db_model.cover_url,
+        }
+    }
+}
+
+impl From<db::Game> for Game {
+    fn from(db_model: db::Game) -> Self {
+        Self {
+            id: db_model.id,
+            name: db_model.name,
+            slug: db_model.slug,
+            date_modified: db_model.date_modified,
+            assets: db_model.assets.map(Into::into),
+            status: db_model.status,
+            api_status: db_model.api_status,
+            sync_at: db_model.sync_at,
+        }
+    }
+}
+
+impl From<db::GameVersion> for GameVersion {
+    fn from(db_model: db::GameVersion) -> Self {
+        Self {
+            id: db_model.id,
+            slug: db_model.slug,
+            name: db_model.name,
+        }
+    }
+}
+
+impl From<db::GameVersionsByType> for GameVersionsByType {
+    fn from(db_model: db::GameVersionsByType) -> Self {
+        Self {
+            version_type: db_model.version_type,
+            versions: db_model.versions.into_iter().map(|v| v.name).collect(),
+            sync_at: db_model.sync_at,
+        }
+    }
+}
+
+impl From<db::GameVersionsByType> for GameVersionsByTypeV2 {
+    fn from(db_model: db::GameVersionsByType) -> Self {
+        Self {
+            version_type: db_model.version_type,
+            versions: db_model.versions.into_iter().map(Into::into).collect(),
+            sync_at: db_model.sync_at,
+        }
+    }
+}
+
+impl From<db::GameVersionType> for GameVersionType {
+    fn from(db_model: db::GameVersionType) -> Self {
+        Self {
+            id: db_model.id,
+            game_id: db_model.game_id,
+            name: db_model.name,
+            slug: db_model.slug,
+            is_syncable: db_model.is_syncable,
+            status: db_model.status,
+            sync_at: db_model.sync_at,
+        }
+    }
+}
+
+impl From<db::MinecraftGameVersion> for MinecraftGameVersion {
+    fn from(db_model: db::MinecraftGameVersion) -> Self {
+        Self {
+            id: db_model.id,
+            game_version_id: db_model.game_version_id,
+            version_string: db_model.version_string,
+            jar_download_url: db_model.jar_download_url,
+            json_download_url: db_model.json_download_url,
+            approved: db_model.approved,
+            date_modified: db_model.date_modified,
+            game_version_type_id: db_model.game_version_type_id,
+            game_version_status: db_model.game_version_status,
+            game_version_type_status: db_model.game_version_type_status,
+            sync_at: db_model.sync_at,
+        }
+    }
+}
+
+impl From<db::MinecraftModLoaderVersion> for MinecraftModLoaderIndex {
+    fn from(db_model: db::MinecraftModLoaderVersion) -> Self {
+        Self {
+            name: db_model.name,
+            game_version: db_model.minecraft_version,
+            latest: db_model.latest,
+            recommended: db_model.recommended,
+            date_modified: db_model.date_modified,
+            loader_type: db_model.loader_type,
+            sync_at: db_model.sync_at,
+        }
+    }
+}
+
+impl From<db::MinecraftModLoaderVersion> for MinecraftModLoaderVersion {
+    fn from(db_model: db::MinecraftModLoaderVersion) -> Self {
+        Self {
+            id: db_model.id,
+            game_version_id: db_model.game_version_id,
+            minecraft_game_version_id: db_model.minecraft_game_version_id,
+            forge_version: db_model.forge_version,
+            name: db_model.name,
+            loader_type: db_model.loader_type,
+            download_url: db_model.download_url,
+            filename: db_model.filename,
+            install_method: db_model.install_method,
+            latest: db_model.latest,
+            recommended: db_model.recommended,
+            approved: db_model.approved,
+            date_modified: db_model.date_modified,
+            maven_version_string: db_model.maven_version_string,
+            version_json: db_model.version_json,
+            libraries_install_location: db_model.libraries_install_location,
+            minecraft_version: db_model.minecraft_version,
+            additional_files_json: db_model.additional_files_json,
+            mod_loader_game_version_id: db_model.mod_loader_game_version_id,
+            mod_loader_game_version_type_id: db_model.mod_loader_game_version_type_id,
+            mod_loader_game_version_status: db_model.mod_loader_game_version_status,
+            mod_loader_game_version_type_status: db_model.mod_loader_game_version_type_status,
+            mc_game_version_id: db_model.mc_game_version_id,
+            mc_game_version_type_id: db_model.mc_game_version_type_id,
+            mc_game_version_status: db_model.mc_game_version_status,
+            mc_game_version_type_status: db_model.mc_game_version_type_status,
+            install_profile_json: db_model.install_profile_json,
+            sync_at: db_model.sync_at,
+        }
+    }
+}
+
 // impl From<db::Fingerprint> for Fingerprint {
 //     fn from(db_model: db::Fingerprint) -> Self {
 //         Self {
@@ -992,4 +1392,97 @@ mod tests {
         assert!(!categories_response.data.is_empty());
         assert!(categories_response.data.iter().all(|c| c.game_id == 432));
     }
+
+    #[tokio::test]
+    async fn test_game_response() {
+        let base_url = get_base_url();
+        let client = reqwest::Client::new();
+        let api_key = get_x_api_key();
+
+        let response = client
+            .get(format!("{}/games/432", base_url)) // Minecraft
+            .header("x-api-key", api_key)
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert!(response.status().is_success());
+
+        let game_response: GameResponse = response
+            .json()
+            .await
+            .expect("Failed to deserialize GameResponse");
+
+        assert_eq!(game_response.data.id, 432);
+    }
+
+    #[tokio::test]
+    async fn test_game_version_types_response() {
+        let base_url = get_base_url();
+        let client = reqwest::Client::new();
+        let api_key = get_x_api_key();
+
+        let response = client
+            .get(format!("{}/games/432/version-types", base_url)) // Minecraft
+            .header("x-api-key", api_key)
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert!(response.status().is_success());
+
+        let version_types_response: GameVersionTypesResponse = response
+            .json()
+            .await
+            .expect("Failed to deserialize GameVersionTypesResponse");
+
+        assert!(!version_types_response.data.is_empty());
+        assert!(version_types_response.data.iter().all(|t| t.game_id == 432));
+    }
+
+    #[tokio::test]
+    async fn test_minecraft_version_response() {
+        let base_url = get_base_url();
+        let client = reqwest::Client::new();
+        let api_key = get_x_api_key();
+
+        let response = client
+            .get(format!("{}/minecraft/version/1.20.1", base_url))
+            .header("x-api-key", api_key)
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert!(response.status().is_success());
+
+        let version_response: MinecraftGameVersionResponse = response
+            .json()
+            .await
+            .expect("Failed to deserialize MinecraftGameVersionResponse");
+
+        assert_eq!(version_response.data.version_string, "1.20.1");
+    }
+
+    #[tokio::test]
+    async fn test_minecraft_modloader_response() {
+        let base_url = get_base_url();
+        let client = reqwest::Client::new();
+        let api_key = get_x_api_key();
+
+        let response = client
+            .get(format!("{}/minecraft/modloader/forge-47.2.0", base_url))
+            .header("x-api-key", api_key)
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert!(response.status().is_success());
+
+        let modloader_response: MinecraftModLoaderVersionResponse = response
+            .json()
+            .await
+            .expect("Failed to deserialize MinecraftModLoaderVersionResponse");
+
+        assert_eq!(modloader_response.data.name, "forge-47.2.0");
+    }
 }
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct ModrinthResolveRequest {
+    pub version_ids: Vec<String>,
+    /// 为空时不限制 loader
+    pub loader: Option<String>,
+    /// 为空时不限制游戏版本
+    pub game_version: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct CurseforgeResolveRequest {
+    #[serde(rename = "fileIds")]
+    pub file_ids: Vec<i32>,
+    /// 为空或为 0 时不限制 loader
+    #[serde(rename = "modLoaderType")]
+    pub mod_loader_type: Option<i32>,
+    /// 为空时不限制游戏版本
+    #[serde(rename = "gameVersion")]
+    pub game_version: Option<String>,
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+// Curseforge 的 modId / fileId 同样以字符串形式放在 project_id / version_id 中
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct ResolvedDependency {
+    pub project_id: String,
+    pub version_id: String,
+    pub filename: Option<String>,
+    pub url: Option<String>,
+    /// 请求中直接给出的版本为 0
+    pub depth: i32,
+    /// 依赖它的 version_id
+    pub required_by: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct DependencyConflict {
+    pub project_id: String,
+    pub selected_version_id: String,
+    pub requested_version_id: String,
+    pub requested_by: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct IncompatibleDependency {
+    pub project_id: String,
+    pub version_id: String,
+    pub incompatible_project_id: String,
+    pub incompatible_version_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct UnresolvedDependency {
+    pub project_id: Option<String>,
+    pub version_id: Option<String>,
+    pub required_by: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, ToSchema)]
+pub struct DependencyResolveResponse {
+    /// 扁平化的安装列表，按解析顺序排列
+    pub install: Vec<ResolvedDependency>,
+    pub conflicts: Vec<DependencyConflict>,
+    /// 安装列表中互相声明 incompatible 的项目
+    pub incompatible: Vec<IncompatibleDependency>,
+    pub unresolved: Vec<UnresolvedDependency>,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 仅用于 OpenAPI 文档，实际按 multipart 逐个字段读取
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct IdentifyUpload {
+    #[schema(value_type = Vec<String>, format = Binary)]
+    pub file: Vec<String>,
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::models::curseforge::responses::Fingerprint as CurseforgeMatch;
+use crate::models::modrinth::responses::Version as ModrinthVersion;
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct IdentifiedFile {
+    pub filename: Option<String>,
+    pub size: i64,
+    /// Curseforge 指纹，可直接用于 /curseforge/v1/fingerprints
+    pub fingerprint: i64,
+    pub sha1: String,
+    pub sha512: String,
+    pub curseforge: Option<CurseforgeMatch>,
+    pub modrinth: Option<ModrinthVersion>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct IdentifyResponse {
+    pub files: Vec<IdentifiedFile>,
+}
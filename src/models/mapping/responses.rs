@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct CurseforgeModMapping {
+    pub mod_id: i32,
+    pub slug: Option<String>,
+    pub name: Option<String>,
+    /// sha1 相同的文件数量
+    pub matched_files: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct ModrinthProjectMapping {
+    pub project_id: String,
+    pub slug: Option<String>,
+    pub title: Option<String>,
+    /// sha1 相同的文件数量
+    pub matched_files: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct ModrinthMappingResponse {
+    pub project_id: String,
+    pub total_files: i64,
+    /// 按 matched_files 降序排列
+    pub curseforge: Vec<CurseforgeModMapping>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct CurseforgeMappingResponse {
+    pub mod_id: i32,
+    pub total_files: i64,
+    /// 按 matched_files 降序排列
+    pub modrinth: Vec<ModrinthProjectMapping>,
+}
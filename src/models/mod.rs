@@ -1,7 +1,14 @@
+pub mod admin;
 pub mod common;
 pub mod curseforge;
+pub mod dependency;
+pub mod identify;
+pub mod mapping;
+pub mod modpack;
 pub mod modrinth;
+pub mod search;
 pub mod translate;
+pub mod user_agent;
 
 use serde::Serialize;
 
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+/// modrinth.index.json 或 Curseforge manifest.json，按 files 的结构区分
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[serde(untagged)]
+pub enum ModpackManifest {
+    Modrinth(ModrinthIndex),
+    Curseforge(CurseforgeManifest),
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct ModrinthIndex {
+    #[serde(rename = "formatVersion")]
+    pub format_version: Option<i32>,
+    pub game: Option<String>,
+    #[serde(rename = "versionId")]
+    pub version_id: Option<String>,
+    pub name: Option<String>,
+    pub files: Vec<ModrinthIndexFile>,
+    pub dependencies: Option<HashMap<String, String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct ModrinthIndexFile {
+    pub path: String,
+    pub hashes: ModrinthIndexHashes,
+    pub env: Option<HashMap<String, String>>,
+    pub downloads: Option<Vec<String>>,
+    #[serde(rename = "fileSize")]
+    pub file_size: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct ModrinthIndexHashes {
+    pub sha1: String,
+    pub sha512: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct CurseforgeManifest {
+    pub minecraft: Option<CurseforgeManifestMinecraft>,
+    #[serde(rename = "manifestType")]
+    pub manifest_type: Option<String>,
+    #[serde(rename = "manifestVersion")]
+    pub manifest_version: Option<i32>,
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub author: Option<String>,
+    pub files: Vec<CurseforgeManifestFile>,
+    pub overrides: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct CurseforgeManifestMinecraft {
+    pub version: String,
+    #[serde(rename = "modLoaders")]
+    pub mod_loaders: Option<Vec<CurseforgeManifestModLoader>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct CurseforgeManifestModLoader {
+    pub id: String,
+    pub primary: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct CurseforgeManifestFile {
+    #[serde(rename = "projectID")]
+    pub project_id: i32,
+    #[serde(rename = "fileID")]
+    pub file_id: i32,
+    pub required: Option<bool>,
+}
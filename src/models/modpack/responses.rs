@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::models::search::responses::Platform;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, ToSchema)]
+pub struct ModpackFileHashes {
+    pub sha1: Option<String>,
+    pub sha512: Option<String>,
+    pub md5: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct ModpackFile {
+    /// mrpack 中的安装路径，Curseforge 整合包为空
+    pub path: Option<String>,
+    /// Modrinth project_id 或 Curseforge modId
+    pub project_id: String,
+    /// Modrinth version_id 或 Curseforge fileId
+    pub version_id: String,
+    pub filename: Option<String>,
+    /// 经过 file_cdn 改写的下载地址
+    pub url: String,
+    pub hashes: ModpackFileHashes,
+    pub size: Option<i64>,
+    pub required: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct UnresolvedModpackFile {
+    pub path: Option<String>,
+    pub project_id: Option<String>,
+    pub version_id: Option<String>,
+    pub sha1: Option<String>,
+    /// mrpack 自带的下载地址，同样经过 file_cdn 改写
+    pub downloads: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct ModpackResolveResponse {
+    pub platform: Platform,
+    pub name: Option<String>,
+    pub game_version: Option<String>,
+    pub files: Vec<ModpackFile>,
+    /// 未缓存的文件，已提交到同步队列
+    pub unresolved: Vec<UnresolvedModpackFile>,
+}
@@ -185,3 +185,100 @@ pub struct GameVersion {
     #[serde_as(as = "FromChrono04DateTime")]
     pub sync_at: DateTime<Utc>,
 }
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct UserInfo {
+    pub id: String,
+    pub username: String,
+    pub name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub bio: Option<String>,
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub created: DateTime<Utc>,
+    pub role: String,
+    pub badges: Option<i64>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct User {
+    #[serde(alias = "_id")]
+    pub id: String,
+    pub username: String,
+    pub name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub bio: Option<String>,
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub created: DateTime<Utc>,
+    pub role: String,
+    pub badges: Option<i64>,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct TeamMember {
+    pub team_id: String,
+    pub user: UserInfo,
+    pub role: String,
+    pub permissions: Option<i64>,
+    pub accepted: bool,
+    pub payouts_split: Option<f64>,
+    pub ordering: Option<i64>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct Team {
+    #[serde(alias = "_id")]
+    pub id: String,
+    pub members: Vec<TeamMember>,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct LicenseTag {
+    pub short: String,
+    pub name: String,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct LicenseText {
+    #[serde(alias = "_id")]
+    pub id: String,
+    pub title: String,
+    pub body: String,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct DonationPlatform {
+    pub short: String,
+    pub name: String,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
+
+// project_type / side_type / report_type 均为纯字符串列表
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+pub struct TagName {
+    pub name: String,
+
+    #[serde_as(as = "FromChrono04DateTime")]
+    pub sync_at: DateTime<Utc>,
+}
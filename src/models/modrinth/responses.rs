@@ -328,6 +328,179 @@ impl From<db::GameVersion> for GameVersion {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct LicenseTag {
+    pub short: String,
+    pub name: String,
+
+    #[serde(default = "Utc::now")]
+    pub sync_at: DateTime<Utc>,
+}
+
+impl From<db::LicenseTag> for LicenseTag {
+    fn from(license: db::LicenseTag) -> Self {
+        LicenseTag {
+            short: license.short,
+            name: license.name,
+
+            sync_at: license.sync_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct LicenseText {
+    pub title: String,
+    pub body: String,
+
+    #[serde(default = "Utc::now")]
+    pub sync_at: DateTime<Utc>,
+}
+
+impl From<db::LicenseText> for LicenseText {
+    fn from(license_text: db::LicenseText) -> Self {
+        LicenseText {
+            title: license_text.title,
+            body: license_text.body,
+
+            sync_at: license_text.sync_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct DonationPlatform {
+    pub short: String,
+    pub name: String,
+
+    #[serde(default = "Utc::now")]
+    pub sync_at: DateTime<Utc>,
+}
+
+impl From<db::DonationPlatform> for DonationPlatform {
+    fn from(platform: db::DonationPlatform) -> Self {
+        DonationPlatform {
+            short: platform.short,
+            name: platform.name,
+
+            sync_at: platform.sync_at,
+        }
+    }
+}
+
+// project_type / side_type / report_type，官方为纯字符串列表
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct TagName {
+    pub name: String,
+
+    #[serde(default = "Utc::now")]
+    pub sync_at: DateTime<Utc>,
+}
+
+impl From<db::TagName> for TagName {
+    fn from(tag: db::TagName) -> Self {
+        TagName {
+            name: tag.name,
+
+            sync_at: tag.sync_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct UserInfo {
+    pub id: String,
+    pub username: String,
+    pub name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub bio: Option<String>,
+    pub created: DateTime<Utc>,
+    pub role: String,
+    pub badges: Option<i64>,
+}
+
+impl From<db::UserInfo> for UserInfo {
+    fn from(user: db::UserInfo) -> Self {
+        UserInfo {
+            id: user.id,
+            username: user.username,
+            name: user.name,
+            avatar_url: user.avatar_url,
+            bio: user.bio,
+            created: user.created,
+            role: user.role,
+            badges: user.badges,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct User {
+    pub id: String,
+    pub username: String,
+    pub name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub bio: Option<String>,
+    pub created: DateTime<Utc>,
+    pub role: String,
+    pub badges: Option<i64>,
+
+    #[serde(default = "Utc::now")]
+    pub sync_at: DateTime<Utc>,
+}
+
+impl From<db::User> for User {
+    fn from(user: db::User) -> Self {
+        User {
+            id: user.id,
+            username: user.username,
+            name: user.name,
+            avatar_url: user.avatar_url,
+            bio: user.bio,
+            created: user.created,
+            role: user.role,
+            badges: user.badges,
+
+            sync_at: user.sync_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct TeamMember {
+    pub team_id: String,
+    pub user: UserInfo,
+    pub role: String,
+    pub permissions: Option<i64>,
+    pub accepted: bool,
+    pub payouts_split: Option<f64>,
+    pub ordering: Option<i64>,
+
+    #[serde(default = "Utc::now")]
+    pub sync_at: DateTime<Utc>,
+}
+
+impl TeamMember {
+    // Team 的 sync_at 下放到每个成员
+    pub fn from_team(team: db::Team) -> Vec<Self> {
+        let sync_at = team.sync_at;
+        team.members
+            .into_iter()
+            .map(|member| TeamMember {
+                team_id: member.team_id,
+                user: member.user.into(),
+                role: member.role,
+                permissions: member.permissions,
+                accepted: member.accepted,
+                payouts_split: member.payouts_split,
+                ordering: member.ordering,
+
+                sync_at,
+            })
+            .collect()
+    }
+}
+
 // macro_rules! impl_from_db {
 //     ($db_type:ty, $resp_type:ty) => {
 //         impl From<$db_type> for $resp_type {
@@ -354,6 +527,12 @@ pub struct MutilFilesResponse {
     pub entries: Option<HashMap<String, Version>>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct ProjectDependencies {
+    pub projects: Vec<Project>,
+    pub versions: Vec<Version>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct CategoriesResponse {
     #[serde(flatten)]
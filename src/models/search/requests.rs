@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct UnifiedSearchQuery {
+    pub query: Option<String>,
+    /// 逗号分隔的平台列表，默认同时搜索 modrinth 和 curseforge
+    pub platforms: Option<String>,
+    pub game_version: Option<String>,
+    pub loader: Option<String>,
+    pub offset: Option<i32>,
+    pub limit: Option<i32>,
+}
@@ -0,0 +1,106 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::models::curseforge::responses as curseforge;
+use crate::models::modrinth::responses as modrinth;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Platform {
+    Modrinth,
+    Curseforge,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct UnifiedSearchHit {
+    pub platform: Platform,
+    pub id: String,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub author: Option<String>,
+    pub icon_url: Option<String>,
+    pub url: Option<String>,
+    pub downloads: i64,
+    pub categories: Vec<String>,
+    pub game_versions: Vec<String>,
+    pub date_created: Option<DateTime<Utc>>,
+    pub date_modified: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct PlatformError {
+    pub platform: Platform,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct UnifiedSearchResponse {
+    pub hits: Vec<UnifiedSearchHit>,
+    pub offset: i32,
+    pub limit: i32,
+    pub total_hits: i64,
+    /// 搜索失败的平台，其余平台的结果仍会正常返回
+    pub errors: Vec<PlatformError>,
+}
+
+impl From<modrinth::SearchHit> for UnifiedSearchHit {
+    fn from(hit: modrinth::SearchHit) -> Self {
+        Self {
+            platform: Platform::Modrinth,
+            url: Some(format!(
+                "https://modrinth.com/{}/{}",
+                hit.project_type, hit.slug
+            )),
+            id: hit.project_id,
+            slug: hit.slug,
+            title: hit.title,
+            description: hit.description,
+            author: Some(hit.author),
+            icon_url: Some(hit.icon_url).filter(|url| !url.is_empty()),
+            downloads: hit.downloads,
+            categories: hit.display_categories.unwrap_or(hit.categories),
+            game_versions: hit.versions,
+            date_created: Some(hit.date_created),
+            date_modified: Some(hit.date_modified),
+        }
+    }
+}
+
+impl From<curseforge::Mod> for UnifiedSearchHit {
+    fn from(m: curseforge::Mod) -> Self {
+        let mut game_versions: Vec<String> = Vec::new();
+        for index in m.latest_files_indexes.iter().flatten() {
+            if let Some(version) = &index.game_version
+                && !game_versions.contains(version)
+            {
+                game_versions.push(version.clone());
+            }
+        }
+
+        Self {
+            platform: Platform::Curseforge,
+            id: m.id.to_string(),
+            title: m.name.unwrap_or_else(|| m.slug.clone()),
+            slug: m.slug,
+            description: m.summary.unwrap_or_default(),
+            author: m
+                .authors
+                .and_then(|authors| authors.into_iter().next())
+                .map(|a| a.name),
+            icon_url: m.logo.and_then(|logo| logo.thumbnail_url),
+            url: m.links.and_then(|links| links.website_url),
+            downloads: m.download_count.unwrap_or(0),
+            categories: m
+                .categories
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|c| c.name)
+                .collect(),
+            game_versions,
+            date_created: m.date_created,
+            date_modified: m.date_modified,
+        }
+    }
+}
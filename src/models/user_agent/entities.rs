@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum UserAgentAction {
+    Allow,
+    Deny,
+}
+
+/// UA 规则，保存在 MongoDB 的 `user_agent_rules` 集合或 `UA_FILTER_FILE` 指定的 JSON 文件中
+///
+/// `pattern` 忽略大小写，支持 `*` 通配符，例如 `HMCL/*`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UserAgentRule {
+    pub pattern: String,
+    pub action: UserAgentAction,
+    #[serde(default)]
+    pub description: Option<String>,
+}
@@ -0,0 +1,176 @@
+use actix_web::middleware::from_fn;
+use actix_web::{Responder, delete, get, post, web};
+
+use crate::errors::ApiError;
+use crate::models::admin::requests::{QueueEntriesQuery, QueueMembersBody};
+use crate::models::admin::responses::{QueueDetail, QueueMutationResponse, QueueSummary};
+use crate::services::queue::QueueService;
+use crate::utils::admin_auth::require_admin;
+use crate::utils::app::AppState;
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/admin").wrap(from_fn(require_admin)).service(
+            web::scope("/queues")
+                .service(list_queues)
+                .service(get_queue)
+                .service(remove_queue_members)
+                .service(requeue_queue_members)
+                .service(clear_queue),
+        ),
+    );
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/queues",
+    responses(
+        (status = 200, description = "Size and enqueue rate of every sync queue", body = Vec<QueueSummary>),
+        (status = 401, description = "Missing or invalid admin token"),
+        (status = 500, description = "Redis is unavailable")
+    ),
+    description = "List sync queues",
+    security(("admin_token" = [])),
+    tag = "Admin"
+)]
+#[get("")]
+async fn list_queues(data: web::Data<AppState>) -> Result<impl Responder, ApiError> {
+    let service = QueueService::new(data.redis_pool.clone());
+
+    match service.list_queues().await {
+        Ok(result) => Ok(web::Json(result)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/queues/{queue}",
+    params(
+        ("queue" = String, Path, description = "Queue name", example = "curseforge_modids"),
+        ("offset" = Option<isize>, Query, description = "Offset of the first entry", example = 0),
+        ("limit" = Option<isize>, Query, description = "Number of entries, at most 1000", example = 100)
+    ),
+    responses(
+        (status = 200, description = "Queue entries ordered by hit count", body = QueueDetail),
+        (status = 400, description = "Invalid offset or limit"),
+        (status = 401, description = "Missing or invalid admin token"),
+        (status = 404, description = "Queue not found"),
+        (status = 500, description = "Redis is unavailable")
+    ),
+    description = "Show the most requested members of a sync queue",
+    security(("admin_token" = [])),
+    tag = "Admin"
+)]
+#[get("/{queue}")]
+async fn get_queue(
+    path: web::Path<String>,
+    query: web::Query<QueueEntriesQuery>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = QueueService::new(data.redis_pool.clone());
+
+    match service.get_queue(&path, query.offset, query.limit).await {
+        Ok(result) => Ok(web::Json(result)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/queues/{queue}/remove",
+    params(
+        ("queue" = String, Path, description = "Queue name", example = "curseforge_modids")
+    ),
+    request_body = QueueMembersBody,
+    responses(
+        (status = 200, description = "Members removed", body = QueueMutationResponse),
+        (status = 400, description = "Invalid members"),
+        (status = 401, description = "Missing or invalid admin token"),
+        (status = 404, description = "Queue not found"),
+        (status = 500, description = "Redis is unavailable")
+    ),
+    description = "Remove members from a sync queue",
+    security(("admin_token" = [])),
+    tag = "Admin"
+)]
+#[post("/{queue}/remove")]
+async fn remove_queue_members(
+    path: web::Path<String>,
+    body: web::Json<QueueMembersBody>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = QueueService::new(data.redis_pool.clone());
+
+    match service
+        .remove_members(&path, body.into_inner().members)
+        .await
+    {
+        Ok(result) => Ok(web::Json(result)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/queues/{queue}/requeue",
+    params(
+        ("queue" = String, Path, description = "Queue name", example = "curseforge_modids")
+    ),
+    request_body = QueueMembersBody,
+    responses(
+        (status = 200, description = "Members requeued", body = QueueMutationResponse),
+        (status = 400, description = "Invalid members"),
+        (status = 401, description = "Missing or invalid admin token"),
+        (status = 404, description = "Queue not found"),
+        (status = 500, description = "Redis is unavailable")
+    ),
+    description = "Put members back into a sync queue",
+    security(("admin_token" = [])),
+    tag = "Admin"
+)]
+#[post("/{queue}/requeue")]
+async fn requeue_queue_members(
+    path: web::Path<String>,
+    body: web::Json<QueueMembersBody>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = QueueService::new(data.redis_pool.clone());
+
+    match service
+        .requeue_members(&path, body.into_inner().members)
+        .await
+    {
+        Ok(result) => Ok(web::Json(result)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/queues/{queue}",
+    params(
+        ("queue" = String, Path, description = "Queue name", example = "curseforge_modids")
+    ),
+    responses(
+        (status = 200, description = "Queue cleared", body = QueueMutationResponse),
+        (status = 401, description = "Missing or invalid admin token"),
+        (status = 404, description = "Queue not found"),
+        (status = 500, description = "Redis is unavailable")
+    ),
+    description = "Clear a sync queue",
+    security(("admin_token" = [])),
+    tag = "Admin"
+)]
+#[delete("/{queue}")]
+async fn clear_queue(
+    path: web::Path<String>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = QueueService::new(data.redis_pool.clone());
+
+    match service.clear_queue(&path).await {
+        Ok(result) => Ok(web::Json(result)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
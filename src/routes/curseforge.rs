@@ -5,23 +5,41 @@ use crate::models::curseforge::requests::*;
 use crate::models::curseforge::responses::*;
 use crate::services::curseforge::CurseforgeService;
 use crate::utils::app::AppState;
-use crate::utils::redis_cache::{cacheable_json, create_key};
+use crate::utils::redis_cache::{
+    ROUTE_CACHE_TTL, RouteCache, SEARCH_CACHE_TTL, TAG_CACHE_TTL, cacheable_json, create_key,
+};
 
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
-        web::scope("/curseforge").service(root).service(
-            web::scope("/v1")
-                .service(search_mods_cached)
-                .service(get_categories)
-                .service(get_mod)
-                .service(get_mods)
-                .service(get_file)
-                .service(get_file_download_url)
-                .service(get_mod_files)
-                .service(get_files_by_ids)
-                .service(get_fingerprints)
-                .service(get_fingerprints_by_game_id),
-        ),
+        web::scope("/curseforge")
+            .service(root)
+            .service(
+                web::scope("/v1")
+                    .service(search_mods_cached)
+                    .service(get_categories)
+                    .service(get_mod)
+                    .service(get_mods)
+                    .service(get_file)
+                    .service(get_file_download_url)
+                    .service(get_mod_description)
+                    .service(get_file_changelog)
+                    .service(get_mod_files)
+                    .service(get_files_by_ids)
+                    .service(get_fingerprints)
+                    // fuzzy 需在 /fingerprints/{game_id} 之前注册
+                    .service(get_fuzzy_fingerprints)
+                    .service(get_fuzzy_fingerprints_by_game_id)
+                    .service(get_fingerprints_by_game_id)
+                    .service(get_games)
+                    .service(get_game)
+                    .service(get_game_versions)
+                    .service(get_game_version_types)
+                    .service(get_minecraft_versions)
+                    .service(get_minecraft_version)
+                    .service(get_minecraft_modloaders)
+                    .service(get_minecraft_modloader),
+            )
+            .service(web::scope("/v2").service(get_game_versions_v2)),
     );
 }
 
@@ -67,7 +85,7 @@ pub async fn root() -> impl Responder {
         (status = 500, description = "Internal server error")
     ),
     tag = "Curseforge",
-    description = "Curseforge Search"
+    description = "Curseforge Search. Depending on `CURSEFORGE_SEARCH_MODE`, results come from the official API, the local mirror, or the local mirror as a fallback when the official API fails."
 )]
 #[get("/mods/search")]
 async fn search_mods_cached(
@@ -79,6 +97,7 @@ async fn search_mods_cached(
     let curseforge_api_url = data.curseforge_api_url.clone();
     let curseforge_api_key = data.curseforge_api_key.clone();
     let http_client = data.http_client.clone();
+    let search_mode = data.curseforge_search_mode;
 
     let key = create_key(
         "GET".to_string(),
@@ -86,25 +105,21 @@ async fn search_mods_cached(
         query.to_string(),
     );
 
-    cacheable_json(
-        redis_pool.clone(),
-        key,
-        3600, // 缓存 1 小时
-        move || {
-            let service = CurseforgeService::new(db.clone(), redis_pool);
-            Box::pin(async move {
-                service
-                    .search_mods(
-                        &http_client,
-                        &query,
-                        &curseforge_api_url,
-                        &curseforge_api_key,
-                    )
-                    .await
-                    .map_err(Into::into)
-            })
-        },
-    )
+    cacheable_json(redis_pool.clone(), key, SEARCH_CACHE_TTL, move || {
+        let service = CurseforgeService::new(db.clone(), redis_pool);
+        Box::pin(async move {
+            service
+                .search_mods_by_mode(
+                    &http_client,
+                    &query,
+                    &curseforge_api_url,
+                    &curseforge_api_key,
+                    search_mode,
+                )
+                .await
+                .map_err(Into::into)
+        })
+    })
     .await
 }
 
@@ -138,7 +153,7 @@ async fn search_mods_cached(
     tag = "Curseforge",
     description = "Curseforge Get Mod by ModId"
 )]
-#[get("/mods/{mod_id}")]
+#[get("/mods/{mod_id}", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
 async fn get_mod(
     path: web::Path<i32>,
     data: web::Data<AppState>,
@@ -168,16 +183,19 @@ async fn get_mod(
         (status = 500, description = "Internal server error")
     ),
     tag = "Curseforge",
-    description = "Curseforge Get Mods by ModIds"
+    description = "Curseforge Get Mods by ModIds. When `filterPcOnly` is true, only mods that have files for a Java Edition game version type (the numbered `Minecraft <version>` types of gameId 432 such as `Minecraft 1.20` or `Minecraft 26.1`, not Modloader, Environment or Bedrock) are returned."
 )]
-#[post("/mods")]
+#[post("/mods", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
 async fn get_mods(
     body: web::Json<ModsBody>,
     data: web::Data<AppState>,
 ) -> Result<impl Responder, ApiError> {
     let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
 
-    match service.get_mods(body.mod_ids.clone()).await {
+    match service
+        .get_mods(body.mod_ids.clone(), body.filter_pc_only)
+        .await
+    {
         Ok(mods) => Ok(web::Json(mods)),
         Err(e) => Err(e.into()),
     }
@@ -189,19 +207,25 @@ async fn get_mods(
     params(
         ("mod_id" = i32, Path, description = "ID of the mod to retrieve files for", example = 238222),
         ("gameVersion" = Option<String>, Query, description = "Game version filter (optional)"),
-        ("modLoaderType" = Option<String>, Query, description = "Mod loader type filter (optional)"),
+        ("modLoaderType" = Option<i32>, Query, description = "Mod loader type filter, 0=Any 1=Forge 2=Cauldron 3=LiteLoader 4=Fabric 5=Quilt 6=NeoForge (optional)"),
+        ("modLoaderTypes" = Option<String>, Query, description = "Comma-separated list of mod loader types, files matching any of them are returned (optional)", example = "1,6"),
+        ("gameVersionTypeId" = Option<i32>, Query, description = "Game version type ID filter (optional)"),
+        ("releaseType" = Option<i32>, Query, description = "Release type filter, 1=Release 2=Beta 3=Alpha (optional)"),
+        ("sortField" = Option<String>, Query, description = "Field to sort by: fileDate, fileName, displayName, downloadCount, fileLength or releaseType (optional, default fileDate)"),
+        ("sortOrder" = Option<String>, Query, description = "Sort order, asc or desc (optional, default desc)"),
         ("index" = Option<i32>, Query, description = "Index for pagination (optional)", example = 0),
         ("pageSize" = Option<i32>, Query, description = "Page size for pagination (optional)", example = 50)
     ),
     responses(
         (status = 200, description = "Files found", body = Vec<FileResponse>),
+        (status = 400, description = "Invalid filter"),
         (status = 404, description = "Mod not found"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Curseforge",
     description = "Curseforge Get Mod Files by ModId"
 )]
-#[get("/mods/{mod_id}/files")]
+#[get("/mods/{mod_id}/files", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
 async fn get_mod_files(
     path: web::Path<i32>,
     query: web::Query<ModFilesQuery>,
@@ -210,16 +234,7 @@ async fn get_mod_files(
     let mod_id = path.into_inner();
     let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
 
-    match service
-        .get_mod_files(
-            mod_id,
-            query.game_version.clone(),
-            query.mod_loader_type,
-            query.index,
-            query.page_size,
-        )
-        .await
-    {
+    match service.get_mod_files(mod_id, query.into_inner()).await {
         Ok(files) => Ok(web::Json(files)),
         Err(e) => Err(e.into()),
     }
@@ -240,7 +255,10 @@ async fn get_mod_files(
     tag = "Curseforge",
     description = "Curseforge Get File Download URL by ModId and FileId"
 )]
-#[get("/mods/{mod_id}/files/{file_id}/download-url")]
+#[get(
+    "/mods/{mod_id}/files/{file_id}/download-url",
+    wrap = "RouteCache::new(ROUTE_CACHE_TTL)"
+)]
 async fn get_file_download_url(
     path: web::Path<(i32, i32)>,
     data: web::Data<AppState>,
@@ -255,6 +273,69 @@ async fn get_file_download_url(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/curseforge/v1/mods/{mod_id}/description",
+    params(
+        ("mod_id" = i32, Path, description = "ID of the mod to retrieve description for")
+    ),
+    responses(
+        (status = 200, description = "Mod description found", body = ModDescriptionResponse),
+        (status = 404, description = "Mod description not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Curseforge",
+    description = "Curseforge Get Mod Description (HTML) by ModId"
+)]
+#[get(
+    "/mods/{mod_id}/description",
+    wrap = "RouteCache::new(ROUTE_CACHE_TTL)"
+)]
+async fn get_mod_description(
+    path: web::Path<i32>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let mod_id = path.into_inner();
+    let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service.get_mod_description(mod_id).await {
+        Ok(description) => Ok(web::Json(description)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/curseforge/v1/mods/{mod_id}/files/{file_id}/changelog",
+    params(
+        ("mod_id" = i32, Path, description = "ID of the mod to which the file belongs"),
+        ("file_id" = i32, Path, description = "ID of the file to retrieve changelog for")
+    ),
+    responses(
+        (status = 200, description = "File changelog found", body = FileChangelogResponse),
+        (status = 404, description = "File changelog not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Curseforge",
+    description = "Curseforge Get File Changelog (HTML) by ModId and FileId"
+)]
+#[get(
+    "/mods/{mod_id}/files/{file_id}/changelog",
+    wrap = "RouteCache::new(ROUTE_CACHE_TTL)"
+)]
+async fn get_file_changelog(
+    path: web::Path<(i32, i32)>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let (mod_id, file_id) = path.into_inner();
+    let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service.get_file_changelog(mod_id, file_id).await {
+        Ok(changelog) => Ok(web::Json(changelog)),
+        Err(e) => Err(e.into()),
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/curseforge/v1/mods/{mod_id}/files/{file_id}",
@@ -270,7 +351,10 @@ async fn get_file_download_url(
     tag = "Curseforge",
     description = "Curseforge Get File by ModId and FileId"
 )]
-#[get("/mods/{mod_id}/files/{file_id}")]
+#[get(
+    "/mods/{mod_id}/files/{file_id}",
+    wrap = "RouteCache::new(ROUTE_CACHE_TTL)"
+)]
 async fn get_file(
     path: web::Path<(i32, i32)>,
     data: web::Data<AppState>,
@@ -297,7 +381,7 @@ async fn get_file(
     tag = "Curseforge",
     description = "Curseforge Get Files by FileIds"
 )]
-#[post("/mods/files")]
+#[post("/mods/files", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
 async fn get_files_by_ids(
     body: web::Json<FileIdsBody>,
     data: web::Data<AppState>,
@@ -322,7 +406,7 @@ async fn get_files_by_ids(
     tag = "Curseforge",
     description = "Curseforge Get Fingerprints by Fingerprints"
 )]
-#[post("/fingerprints")]
+#[post("/fingerprints", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
 async fn get_fingerprints(
     body: web::Json<FingerprintsBody>,
     data: web::Data<AppState>,
@@ -353,7 +437,7 @@ async fn get_fingerprints(
     tag = "Curseforge",
     description = "Curseforge Get Fingerprints by GameId and Fingerprints"
 )]
-#[post("/fingerprints/{game_id}")]
+#[post("/fingerprints/{game_id}", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
 async fn get_fingerprints_by_game_id(
     path: web::Path<i32>,
     body: web::Json<FingerprintsBody>,
@@ -372,6 +456,72 @@ async fn get_fingerprints_by_game_id(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/curseforge/v1/fingerprints/fuzzy",
+    request_body = FuzzyFingerprintsBody,
+    responses(
+        (status = 200, description = "Fuzzy matches found", body = FuzzyFingerprintResponse),
+        (status = 400, description = "Invalid request body"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Curseforge",
+    description = "Curseforge Get Fuzzy Fingerprint Matches by folder fingerprints"
+)]
+#[post("/fingerprints/fuzzy", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
+async fn get_fuzzy_fingerprints(
+    body: web::Json<FuzzyFingerprintsBody>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let body = body.into_inner();
+    let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service
+        .get_fuzzy_fingerprints(body.fingerprints, body.game_id)
+        .await
+    {
+        Ok(fuzzy_result) => Ok(web::Json(fuzzy_result)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/curseforge/v1/fingerprints/fuzzy/{game_id}",
+    params(
+        ("game_id" = i32, Path, description = "ID of the game to filter fingerprints by")
+    ),
+    request_body = FuzzyFingerprintsBody,
+    responses(
+        (status = 200, description = "Fuzzy matches found", body = FuzzyFingerprintResponse),
+        (status = 400, description = "Invalid request body"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Curseforge",
+    description = "Curseforge Get Fuzzy Fingerprint Matches by GameId and folder fingerprints"
+)]
+#[post(
+    "/fingerprints/fuzzy/{game_id}",
+    wrap = "RouteCache::new(ROUTE_CACHE_TTL)"
+)]
+async fn get_fuzzy_fingerprints_by_game_id(
+    path: web::Path<i32>,
+    body: web::Json<FuzzyFingerprintsBody>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let game_id = path.into_inner();
+    let body = body.into_inner();
+    let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service
+        .get_fuzzy_fingerprints(body.fingerprints, Some(game_id))
+        .await
+    {
+        Ok(fuzzy_result) => Ok(web::Json(fuzzy_result)),
+        Err(e) => Err(e.into()),
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/curseforge/v1/categories",
@@ -387,7 +537,7 @@ async fn get_fingerprints_by_game_id(
     tag = "Curseforge",
     description = "Curseforge Get Categories by GameId and ClassId"
 )]
-#[get("/categories")]
+#[get("/categories", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
 async fn get_categories(
     query: web::Query<CategoriesQuery>,
     data: web::Data<AppState>,
@@ -402,3 +552,263 @@ async fn get_categories(
         Err(e) => Err(e.into()),
     }
 }
+
+#[utoipa::path(
+    get,
+    path = "/curseforge/v1/games",
+    params(
+        ("index" = Option<i32>, Query, description = "Zero-based index of the first item to include in the response.", example = 0, minimum = 0),
+        ("pageSize" = Option<i32>, Query, description = "Number of items to include in the response. The default/maximum value is 50.", example = 50, maximum = 50, minimum = 1)
+    ),
+    responses(
+        (status = 200, description = "Games found", body = GamesResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Curseforge",
+    description = "Curseforge Get Games"
+)]
+#[get("/games", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
+async fn get_games(
+    query: web::Query<GamesQuery>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service.get_games(query.index, query.page_size).await {
+        Ok(games) => Ok(web::Json(games)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/curseforge/v1/games/{game_id}",
+    params(
+        ("game_id" = i32, Path, description = "ID of the game", example = 432)
+    ),
+    responses(
+        (status = 200, description = "Game found", body = GameResponse),
+        (status = 404, description = "Game not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Curseforge",
+    description = "Curseforge Get Game by GameId"
+)]
+#[get("/games/{game_id}", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
+async fn get_game(
+    path: web::Path<i32>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let game_id = path.into_inner();
+    let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service.get_game(game_id).await {
+        Ok(game) => Ok(web::Json(game)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/curseforge/v1/games/{game_id}/versions",
+    params(
+        ("game_id" = i32, Path, description = "ID of the game", example = 432)
+    ),
+    responses(
+        (status = 200, description = "Game versions found", body = GameVersionsResponse),
+        (status = 404, description = "Game not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Curseforge",
+    description = "Curseforge Get Game Versions grouped by version type"
+)]
+#[get("/games/{game_id}/versions", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
+async fn get_game_versions(
+    path: web::Path<i32>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let game_id = path.into_inner();
+    let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service.get_game_versions(game_id).await {
+        Ok(versions) => Ok(web::Json(versions)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/curseforge/v2/games/{game_id}/versions",
+    params(
+        ("game_id" = i32, Path, description = "ID of the game", example = 432)
+    ),
+    responses(
+        (status = 200, description = "Game versions found", body = GameVersionsV2Response),
+        (status = 404, description = "Game not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Curseforge",
+    description = "Curseforge Get Game Versions (V2) grouped by version type, including version ids and slugs"
+)]
+#[get("/games/{game_id}/versions", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
+async fn get_game_versions_v2(
+    path: web::Path<i32>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let game_id = path.into_inner();
+    let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service.get_game_versions_v2(game_id).await {
+        Ok(versions) => Ok(web::Json(versions)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/curseforge/v1/games/{game_id}/version-types",
+    params(
+        ("game_id" = i32, Path, description = "ID of the game", example = 432)
+    ),
+    responses(
+        (status = 200, description = "Game version types found", body = GameVersionTypesResponse),
+        (status = 404, description = "Game not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Curseforge",
+    description = "Curseforge Get Game Version Types"
+)]
+#[get(
+    "/games/{game_id}/version-types",
+    wrap = "RouteCache::new(TAG_CACHE_TTL)"
+)]
+async fn get_game_version_types(
+    path: web::Path<i32>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let game_id = path.into_inner();
+    let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service.get_game_version_types(game_id).await {
+        Ok(version_types) => Ok(web::Json(version_types)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/curseforge/v1/minecraft/version",
+    params(
+        ("sortDescending" = Option<bool>, Query, description = "Whether to sort versions in descending order (optional)")
+    ),
+    responses(
+        (status = 200, description = "Minecraft versions found", body = MinecraftGameVersionsResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Curseforge",
+    description = "Curseforge Get Minecraft Versions"
+)]
+#[get("/minecraft/version", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
+async fn get_minecraft_versions(
+    query: web::Query<MinecraftVersionsQuery>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service.get_minecraft_versions(query.sort_descending).await {
+        Ok(versions) => Ok(web::Json(versions)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/curseforge/v1/minecraft/version/{game_version}",
+    params(
+        ("game_version" = String, Path, description = "Minecraft version string", example = "1.20.1")
+    ),
+    responses(
+        (status = 200, description = "Minecraft version found", body = MinecraftGameVersionResponse),
+        (status = 404, description = "Minecraft version not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Curseforge",
+    description = "Curseforge Get Minecraft Version by version string"
+)]
+#[get(
+    "/minecraft/version/{game_version}",
+    wrap = "RouteCache::new(TAG_CACHE_TTL)"
+)]
+async fn get_minecraft_version(
+    path: web::Path<String>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let game_version = path.into_inner();
+    let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service.get_minecraft_version(game_version).await {
+        Ok(version) => Ok(web::Json(version)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/curseforge/v1/minecraft/modloader",
+    params(
+        ("version" = Option<String>, Query, description = "Minecraft version to filter modloaders by (optional)"),
+        ("includeAll" = Option<bool>, Query, description = "Whether to include all modloader versions instead of only latest and recommended (optional)")
+    ),
+    responses(
+        (status = 200, description = "Minecraft modloaders found", body = MinecraftModLoaderIndexResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Curseforge",
+    description = "Curseforge Get Minecraft ModLoaders"
+)]
+#[get("/minecraft/modloader", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
+async fn get_minecraft_modloaders(
+    query: web::Query<MinecraftModLoadersQuery>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service
+        .get_minecraft_modloaders(query.version.clone(), query.include_all)
+        .await
+    {
+        Ok(modloaders) => Ok(web::Json(modloaders)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/curseforge/v1/minecraft/modloader/{mod_loader_name}",
+    params(
+        ("mod_loader_name" = String, Path, description = "Name of the modloader version", example = "forge-47.2.0")
+    ),
+    responses(
+        (status = 200, description = "Minecraft modloader found", body = MinecraftModLoaderVersionResponse),
+        (status = 404, description = "Minecraft modloader not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Curseforge",
+    description = "Curseforge Get Minecraft ModLoader by name"
+)]
+#[get(
+    "/minecraft/modloader/{mod_loader_name}",
+    wrap = "RouteCache::new(TAG_CACHE_TTL)"
+)]
+async fn get_minecraft_modloader(
+    path: web::Path<String>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let mod_loader_name = path.into_inner();
+    let service = CurseforgeService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service.get_minecraft_modloader(mod_loader_name).await {
+        Ok(modloader) => Ok(web::Json(modloader)),
+        Err(e) => Err(e.into()),
+    }
+}
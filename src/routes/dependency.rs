@@ -0,0 +1,73 @@
+use actix_web::{Responder, post, web};
+
+use crate::errors::ApiError;
+use crate::models::dependency::requests::{CurseforgeResolveRequest, ModrinthResolveRequest};
+use crate::models::dependency::responses::DependencyResolveResponse;
+use crate::services::dependency::DependencyService;
+use crate::utils::app::AppState;
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/dependencies")
+            .service(resolve_modrinth_dependencies)
+            .service(resolve_curseforge_dependencies),
+    );
+}
+
+#[utoipa::path(
+    post,
+    path = "/dependencies/modrinth",
+    request_body = ModrinthResolveRequest,
+    responses(
+        (status = 200, description = "Flattened install plan", body = DependencyResolveResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 500, description = "Internal server error")
+    ),
+    description = "Resolve required dependencies of Modrinth versions transitively, picking the newest version compatible with the loader and game version",
+    tag = "Dependencies"
+)]
+#[post("/modrinth")]
+async fn resolve_modrinth_dependencies(
+    body: web::Json<ModrinthResolveRequest>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let body = body.into_inner();
+    let service = DependencyService::new(data.db.clone());
+
+    match service
+        .resolve_modrinth(body.version_ids, body.loader, body.game_version)
+        .await
+    {
+        Ok(result) => Ok(web::Json(result)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/dependencies/curseforge",
+    request_body = CurseforgeResolveRequest,
+    responses(
+        (status = 200, description = "Flattened install plan, modId and fileId are returned as strings", body = DependencyResolveResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 500, description = "Internal server error")
+    ),
+    description = "Resolve required dependencies of CurseForge files transitively, picking the newest file compatible with the mod loader and game version",
+    tag = "Dependencies"
+)]
+#[post("/curseforge")]
+async fn resolve_curseforge_dependencies(
+    body: web::Json<CurseforgeResolveRequest>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let body = body.into_inner();
+    let service = DependencyService::new(data.db.clone());
+
+    match service
+        .resolve_curseforge(body.file_ids, body.mod_loader_type, body.game_version)
+        .await
+    {
+        Ok(result) => Ok(web::Json(result)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
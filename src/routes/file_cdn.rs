@@ -1,7 +1,9 @@
 use actix_web::{Responder, route, web, web::Redirect, http::StatusCode};
 
 use crate::utils::app::AppState;
-use crate::utils::file_cdn_load_balance::select_cdn_endpoint;
+use crate::utils::file_cdn_load_balance::{
+    curseforge_file_url, modrinth_file_url, select_cdn_endpoint,
+};
 
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(get_modrinth_file)
@@ -36,28 +38,7 @@ pub async fn get_curseforge_file(
     data: web::Data<AppState>,
 ) -> impl Responder {
     let (file_id1, file_id2, file_name) = path.into_inner();
-
-    if data.file_cdn_enabled == false {
-        let url = format!(
-            "{}/files/{}/{}/{}",
-            data.curseforge_file_cdn_fallback_url.clone(),
-            file_id1,
-            file_id2,
-            file_name
-        );
-        return Redirect::to(url).using_status_code(StatusCode::FOUND);
-    }
-
-    let mirror_url = select_cdn_endpoint(
-        data.curseforge_file_cdn_url.clone(),
-        data.curseforge_file_cdn_fallback_url.clone(),
-        data.curseforge_cdn_primary_percentage,
-    );
-    let encoded_file_name = urlencoding::encode(&file_name).to_string();
-    let url = format!(
-        "{}/files/{}/{}/{}",
-        mirror_url, file_id1, file_id2, encoded_file_name
-    );
+    let url = curseforge_file_url(&data, &file_id1, &file_id2, &file_name);
 
     // Redirect to the constructed URL
     Redirect::to(url).using_status_code(StatusCode::FOUND)
@@ -88,27 +69,7 @@ pub async fn get_modrinth_file(
     data: web::Data<AppState>,
 ) -> impl Responder {
     let (project_id, version_id, file_name) = path.into_inner();
-    if data.file_cdn_enabled == false {
-        let url = format!(
-            "{}/data/{}/versions/{}/{}",
-            data.modrinth_file_cdn_fallback_url.clone(),
-            project_id,
-            version_id,
-            file_name
-        );
-        return Redirect::to(url).using_status_code(StatusCode::FOUND);
-    }
-
-    let mirror_url = select_cdn_endpoint(
-        data.modrinth_file_cdn_url.clone(),
-        data.modrinth_file_cdn_fallback_url.clone(),
-        data.modrinth_cdn_primary_percentage,
-    );
-
-    let url = format!(
-        "{}/data/{}/versions/{}/{}",
-        mirror_url, project_id, version_id, file_name
-    );
+    let url = modrinth_file_url(&data, &project_id, &version_id, &file_name);
 
     // Redirect to the constructed URL
     Redirect::to(url).using_status_code(StatusCode::FOUND)
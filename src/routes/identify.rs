@@ -0,0 +1,112 @@
+use actix_multipart::Multipart;
+use actix_web::{HttpRequest, Responder, http::header, post, web};
+use bytes::BytesMut;
+use futures::stream::TryStreamExt;
+
+use crate::errors::ApiError;
+use crate::models::identify::requests::IdentifyUpload;
+use crate::models::identify::responses::IdentifyResponse;
+use crate::services::identify::{IdentifyService, UploadedFile};
+use crate::utils::app::AppState;
+
+const MAX_UPLOAD_FILES: usize = 32;
+// 绝大多数 mod jar 远小于该值
+const MAX_UPLOAD_FILE_SIZE: usize = 32 * 1024 * 1024;
+// 所有文件合计的上限，限制单个请求占用的内存
+const MAX_UPLOAD_TOTAL_SIZE: usize = 64 * 1024 * 1024;
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(identify_files);
+}
+
+#[utoipa::path(
+    post,
+    path = "/identify",
+    request_body(content = IdentifyUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Hashes and matches of each uploaded file", body = IdentifyResponse),
+        (status = 400, description = "Invalid upload"),
+        (status = 500, description = "Internal server error")
+    ),
+    description = "Compute CurseForge fingerprint, sha1 and sha512 for uploaded files and look them up on both platforms",
+    tag = "Identify"
+)]
+#[post("/identify")]
+async fn identify_files(
+    req: HttpRequest,
+    mut payload: Multipart,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    // Content-Length 已超出上限时不读取 body
+    if req
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok())
+        .is_some_and(|length| length > MAX_UPLOAD_TOTAL_SIZE)
+    {
+        return Err(upload_too_large());
+    }
+
+    let mut files = Vec::new();
+    let mut total_size = 0;
+
+    while let Some(mut field) = payload
+        .try_next()
+        .await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?
+    {
+        // 忽略非文件字段
+        let Some(filename) = field
+            .content_disposition()
+            .and_then(|cd| cd.get_filename())
+            .map(String::from)
+        else {
+            continue;
+        };
+
+        if files.len() >= MAX_UPLOAD_FILES {
+            return Err(ApiError::BadRequest(format!(
+                "At most {} files can be uploaded at once",
+                MAX_UPLOAD_FILES
+            )));
+        }
+
+        let mut buffer = BytesMut::new();
+        while let Some(chunk) = field
+            .try_next()
+            .await
+            .map_err(|e| ApiError::BadRequest(e.to_string()))?
+        {
+            if buffer.len() + chunk.len() > MAX_UPLOAD_FILE_SIZE {
+                return Err(ApiError::BadRequest(format!(
+                    "File {} exceeds {} bytes",
+                    filename, MAX_UPLOAD_FILE_SIZE
+                )));
+            }
+            total_size += chunk.len();
+            if total_size > MAX_UPLOAD_TOTAL_SIZE {
+                return Err(upload_too_large());
+            }
+            buffer.extend_from_slice(&chunk);
+        }
+
+        files.push(UploadedFile {
+            filename: Some(filename),
+            data: buffer.freeze(),
+        });
+    }
+
+    let service = IdentifyService::new(data.db.clone(), data.redis_pool.clone());
+    match service.identify(files).await {
+        Ok(result) => Ok(web::Json(result)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+fn upload_too_large() -> ApiError {
+    ApiError::BadRequest(format!(
+        "Upload exceeds {} bytes in total",
+        MAX_UPLOAD_TOTAL_SIZE
+    ))
+}
@@ -0,0 +1,71 @@
+use actix_web::{Responder, get, web};
+
+use crate::errors::ApiError;
+use crate::models::mapping::responses::{CurseforgeMappingResponse, ModrinthMappingResponse};
+use crate::services::mapping::MappingService;
+use crate::utils::app::AppState;
+use crate::utils::redis_cache::{ROUTE_CACHE_TTL, RouteCache};
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/mapping")
+            .service(get_modrinth_mapping)
+            .service(get_curseforge_mapping),
+    );
+}
+
+#[utoipa::path(
+    get,
+    path = "/mapping/modrinth/{project_id}",
+    params(
+        ("project_id" = String, Path, description = "Project ID or slug of the Modrinth project", example = "Wnxd13zP")
+    ),
+    responses(
+        (status = 200, description = "CurseForge mods sharing files with the project", body = ModrinthMappingResponse),
+        (status = 404, description = "Project not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    description = "Find CurseForge mods matching a Modrinth project by file sha1",
+    tag = "Mapping"
+)]
+#[get("/modrinth/{project_id}", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
+async fn get_modrinth_mapping(
+    path: web::Path<String>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let project_id = path.into_inner();
+    let service = MappingService::new(data.db.clone());
+
+    match service.get_modrinth_mapping(&project_id).await {
+        Ok(result) => Ok(web::Json(result)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/mapping/curseforge/{mod_id}",
+    params(
+        ("mod_id" = i32, Path, description = "Mod ID of the CurseForge mod", example = 594678)
+    ),
+    responses(
+        (status = 200, description = "Modrinth projects sharing files with the mod", body = CurseforgeMappingResponse),
+        (status = 404, description = "Mod not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    description = "Find Modrinth projects matching a CurseForge mod by file sha1",
+    tag = "Mapping"
+)]
+#[get("/curseforge/{mod_id}", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
+async fn get_curseforge_mapping(
+    path: web::Path<i32>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let mod_id = path.into_inner();
+    let service = MappingService::new(data.db.clone());
+
+    match service.get_curseforge_mapping(mod_id).await {
+        Ok(result) => Ok(web::Json(result)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
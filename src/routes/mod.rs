@@ -1,8 +1,14 @@
+pub mod admin;
 pub mod common;
 pub mod curseforge;
+pub mod dependency;
 pub mod file_cdn;
+pub mod identify;
+pub mod mapping;
+pub mod modpack;
 pub mod modrinth;
 pub mod openapi;
+pub mod search;
 pub mod translate;
 
 use actix_web::web;
@@ -13,6 +19,12 @@ pub fn config(cfg: &mut web::ServiceConfig) {
         .configure(translate::config)
         .configure(curseforge::config)
         .configure(modrinth::config)
+        .configure(search::config)
+        .configure(mapping::config)
+        .configure(dependency::config)
+        .configure(modpack::config)
+        .configure(identify::config)
+        .configure(admin::config)
         .configure(file_cdn::config)
         .route("/openapi.json", web::get().to(openapi::serve_openapi));
 }
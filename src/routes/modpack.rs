@@ -0,0 +1,47 @@
+use actix_web::{Responder, post, web};
+
+use crate::errors::ApiError;
+use crate::models::modpack::requests::ModpackManifest;
+use crate::models::modpack::responses::ModpackResolveResponse;
+use crate::services::modpack::ModpackService;
+use crate::utils::app::AppState;
+
+// 大型整合包的清单可能超过默认的 32KB 限制
+const MANIFEST_SIZE_LIMIT: usize = 4 * 1024 * 1024;
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/modpack")
+            .app_data(
+                web::JsonConfig::default()
+                    .limit(MANIFEST_SIZE_LIMIT)
+                    .error_handler(|err, _| ApiError::BadRequest(err.to_string()).into()),
+            )
+            .service(resolve_modpack),
+    );
+}
+
+#[utoipa::path(
+    post,
+    path = "/modpack/resolve",
+    request_body = ModpackManifest,
+    responses(
+        (status = 200, description = "Resolved files with download URLs, hashes and sizes", body = ModpackResolveResponse),
+        (status = 400, description = "Invalid manifest"),
+        (status = 500, description = "Internal server error")
+    ),
+    description = "Resolve a Modrinth modrinth.index.json or a CurseForge manifest.json, download URLs are rewritten to the file CDN",
+    tag = "Modpack"
+)]
+#[post("/resolve")]
+async fn resolve_modpack(
+    body: web::Json<ModpackManifest>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = ModpackService::new(data.db.clone(), data.redis_pool.clone());
+
+    match service.resolve(&data, body.into_inner()).await {
+        Ok(result) => Ok(web::Json(result)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
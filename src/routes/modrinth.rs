@@ -6,7 +6,9 @@ use crate::models::modrinth::requests::*;
 use crate::models::modrinth::responses::*;
 use crate::services::modrinth::ModrinthService;
 use crate::utils::app::AppState;
-use crate::utils::redis_cache::{cacheable_json, create_key};
+use crate::utils::redis_cache::{
+    ROUTE_CACHE_TTL, RouteCache, SEARCH_CACHE_TTL, TAG_CACHE_TTL, cacheable_json, create_key,
+};
 
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -16,6 +18,13 @@ pub fn config(cfg: &mut web::ServiceConfig) {
                 .service(get_project)
                 .service(get_projects)
                 .service(get_project_versions)
+                .service(get_project_version)
+                .service(get_project_dependencies)
+                .service(get_project_members)
+                .service(get_team_members)
+                .service(get_teams)
+                .service(get_user)
+                .service(get_user_projects)
                 .service(get_version)
                 .service(get_versions)
                 .service(get_version_file)
@@ -24,7 +33,13 @@ pub fn config(cfg: &mut web::ServiceConfig) {
                 .service(update_version_files)
                 .service(get_categories)
                 .service(get_loaders)
-                .service(get_game_versions),
+                .service(get_game_versions)
+                .service(get_licenses)
+                .service(get_license_text)
+                .service(get_donation_platforms)
+                .service(get_project_types)
+                .service(get_side_types)
+                .service(get_report_types),
         ),
     );
 }
@@ -69,6 +84,7 @@ pub async fn search_cached(
     let db = data.db.clone();
     let modrinth_api_url = data.modrinth_api_url.clone();
     let http_client = data.http_client.clone();
+    let search_mode = data.modrinth_search_mode;
 
     let key = create_key(
         "GET".to_string(),
@@ -76,28 +92,15 @@ pub async fn search_cached(
         query.to_string(),
     );
 
-    cacheable_json(
-        redis_pool.clone(),
-        key,
-        3600, // 缓存 1 小时
-        move || {
-            Box::pin(async move {
-                let service = ModrinthService::new(db, redis_pool);
-                service
-                    .search(
-                        &http_client,
-                        query.query,
-                        query.facets,
-                        query.offset,
-                        query.limit,
-                        query.index,
-                        &modrinth_api_url,
-                    )
-                    .await
-                    .map_err(|e| e.into())
-            })
-        },
-    )
+    cacheable_json(redis_pool.clone(), key, SEARCH_CACHE_TTL, move || {
+        Box::pin(async move {
+            let service = ModrinthService::new(db, redis_pool);
+            service
+                .search_by_mode(&http_client, &query, &modrinth_api_url, search_mode)
+                .await
+                .map_err(|e| e.into())
+        })
+    })
     .await
 }
 
@@ -131,7 +134,7 @@ pub async fn search_cached(
     tag = "Modrinth",
     description = "Get a project by its project_id or slug"
 )]
-#[get("/project/{project_id}")]
+#[get("/project/{project_id}", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
 pub async fn get_project(
     idslug: web::Path<String>,
     data: web::Data<AppState>,
@@ -155,7 +158,7 @@ pub async fn get_project(
     tag = "Modrinth",
     description = "Get multiple projects by their project_ids or slugs"
 )]
-#[get("/projects")]
+#[get("/projects", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
 pub async fn get_projects(
     web::Query(project_ids): web::Query<ProjectIds>,
     data: web::Data<AppState>,
@@ -185,7 +188,10 @@ pub async fn get_projects(
     tag = "Modrinth",
     description = "Get all versions of a project by its project_id or slug"
 )]
-#[get("/project/{project_id}/version")]
+#[get(
+    "/project/{project_id}/version",
+    wrap = "RouteCache::new(ROUTE_CACHE_TTL)"
+)]
 pub async fn get_project_versions(
     idslug: web::Path<String>,
     query: web::Query<ProjectVersionQuery>,
@@ -214,6 +220,191 @@ pub async fn get_project_versions(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/modrinth/v2/project/{project_id}/version/{version}",
+    params(
+        ("project_id" = String, Path, description = "ID or slug of the project", example = "sodium"),
+        ("version" = String, Path, description = "ID or version number of the version", example = "mc1.20.1-0.5.3")
+    ),
+    responses(
+        (status = 200, description = "Version found", body = Version)
+    ),
+    tag = "Modrinth",
+    description = "Get a specific version of a project by its version_id or version_number"
+)]
+#[get(
+    "/project/{project_id}/version/{version}",
+    wrap = "RouteCache::new(ROUTE_CACHE_TTL)"
+)]
+pub async fn get_project_version(
+    path: web::Path<(String, String)>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let (idslug, version) = path.into_inner();
+    let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
+    match service.get_project_version(idslug, version).await {
+        Ok(version) => Ok(web::Json(version)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/modrinth/v2/project/{project_id}/dependencies",
+    params(
+        ("project_id" = String, Path, description = "ID or slug of the project", example = "sodium")
+    ),
+    responses(
+        (status = 200, description = "Project dependencies found", body = ProjectDependencies)
+    ),
+    tag = "Modrinth",
+    description = "Get all dependencies of a project by its project_id or slug"
+)]
+#[get(
+    "/project/{project_id}/dependencies",
+    wrap = "RouteCache::new(ROUTE_CACHE_TTL)"
+)]
+pub async fn get_project_dependencies(
+    idslug: web::Path<String>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
+    match service.get_project_dependencies(idslug.into_inner()).await {
+        Ok(dependencies) => Ok(web::Json(dependencies)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/modrinth/v2/project/{project_id}/members",
+    params(
+        ("project_id" = String, Path, description = "ID or slug of the project", example = "sodium")
+    ),
+    responses(
+        (status = 200, description = "Team members found", body = Vec<TeamMember>)
+    ),
+    tag = "Modrinth",
+    description = "Get the team members of a project by its project_id or slug"
+)]
+#[get(
+    "/project/{project_id}/members",
+    wrap = "RouteCache::new(ROUTE_CACHE_TTL)"
+)]
+pub async fn get_project_members(
+    idslug: web::Path<String>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
+    match service.get_project_members(idslug.into_inner()).await {
+        Ok(members) => Ok(web::Json(members)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/modrinth/v2/team/{team_id}/members",
+    params(
+        ("team_id" = String, Path, description = "ID of the team", example = "twbRaNO5")
+    ),
+    responses(
+        (status = 200, description = "Team members found", body = Vec<TeamMember>)
+    ),
+    tag = "Modrinth",
+    description = "Get the members of a team by its team_id"
+)]
+#[get("/team/{team_id}/members", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
+pub async fn get_team_members(
+    team_id: web::Path<String>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
+    match service.get_team_members(team_id.into_inner()).await {
+        Ok(members) => Ok(web::Json(members)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/modrinth/v2/teams",
+    params(
+        ("ids" = String, Query, description = "The IDs of the teams", example = "[\"twbRaNO5\", \"3TidTIHz\"]")
+    ),
+    responses(
+        (status = 200, description = "Teams found", body = Vec<Vec<TeamMember>>)
+    ),
+    tag = "Modrinth",
+    description = "Get the members of multiple teams by their team_ids"
+)]
+#[get("/teams", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
+pub async fn get_teams(
+    web::Query(team_ids): web::Query<ProjectIds>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let ids: Vec<&str> = serde_json::from_str(&team_ids.ids)
+        .map_err(|_| ApiError::BadRequest("Invalid JSON format for ids".to_string()))?;
+
+    let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
+    match service
+        .get_teams(ids.into_iter().map(|s| s.to_string()).collect())
+        .await
+    {
+        Ok(teams) => Ok(web::Json(teams)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/modrinth/v2/user/{user_id}",
+    params(
+        ("user_id" = String, Path, description = "ID or username of the user", example = "l45nT5ov")
+    ),
+    responses(
+        (status = 200, description = "User found", body = User)
+    ),
+    tag = "Modrinth",
+    description = "Get a user by its user_id or username"
+)]
+#[get("/user/{user_id}", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
+pub async fn get_user(
+    idname: web::Path<String>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
+    match service.get_user(idname.into_inner()).await {
+        Ok(user) => Ok(web::Json(user)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/modrinth/v2/user/{user_id}/projects",
+    params(
+        ("user_id" = String, Path, description = "ID or username of the user", example = "l45nT5ov")
+    ),
+    responses(
+        (status = 200, description = "User projects found", body = Vec<Project>)
+    ),
+    tag = "Modrinth",
+    description = "Get all projects of a user by its user_id or username"
+)]
+#[get("/user/{user_id}/projects", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
+pub async fn get_user_projects(
+    idname: web::Path<String>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
+    match service.get_user_projects(idname.into_inner()).await {
+        Ok(projects) => Ok(web::Json(projects)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/modrinth/v2/version/{version_id}",
@@ -226,7 +417,7 @@ pub async fn get_project_versions(
     tag = "Modrinth",
     description = "Get a specific version by version_id"
 )]
-#[get("/version/{version_id}")]
+#[get("/version/{version_id}", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
 pub async fn get_version(
     version_id: web::Path<String>,
     data: web::Data<AppState>,
@@ -250,7 +441,7 @@ pub async fn get_version(
     tag = "Modrinth",
     description = "Get multiple versions by their version_ids"
 )]
-#[get("/versions")]
+#[get("/versions", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
 pub async fn get_versions(
     web::Query(version_ids): web::Query<ProjectIds>,
     data: web::Data<AppState>,
@@ -281,7 +472,7 @@ pub async fn get_versions(
     tag = "Modrinth",
     description = "Get a version file by its hash"
 )]
-#[get("/version_file/{hash}")]
+#[get("/version_file/{hash}", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
 pub async fn get_version_file(
     hash: web::Path<String>,
     query: web::Query<AlgorithmItems>,
@@ -307,7 +498,7 @@ pub async fn get_version_file(
     tag = "Modrinth",
     description = "Get multiple version files by their hashes"
 )]
-#[post("/version_files")]
+#[post("/version_files", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
 pub async fn get_version_files(
     body: web::Json<HashesQuery>,
     data: web::Data<AppState>,
@@ -336,7 +527,10 @@ pub async fn get_version_files(
     tag = "Modrinth",
     description = "Update a version file by its hash with new loaders and game versions"
 )]
-#[post("/version_file/{hash}/update")]
+#[post(
+    "/version_file/{hash}/update",
+    wrap = "RouteCache::new(ROUTE_CACHE_TTL)"
+)]
 pub async fn update_version_file(
     hash: web::Path<String>,
     body: web::Json<UpdateItems>,
@@ -368,7 +562,7 @@ pub async fn update_version_file(
     tag = "Modrinth",
     description = "Update multiple version files by their hashes with new loaders and game versions"
 )]
-#[post("/version_files/update")]
+#[post("/version_files/update", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
 pub async fn update_version_files(
     body: web::Json<MultiUpdateItems>,
     data: web::Data<AppState>,
@@ -397,7 +591,7 @@ pub async fn update_version_files(
     tag = "Modrinth",
     description = "Get all categories"
 )]
-#[get("/tag/category")]
+#[get("/tag/category", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
 pub async fn get_categories(data: web::Data<AppState>) -> Result<impl Responder, ApiError> {
     let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
     match service.get_categories().await {
@@ -415,7 +609,7 @@ pub async fn get_categories(data: web::Data<AppState>) -> Result<impl Responder,
     tag = "Modrinth",
     description = "Get all loaders"
 )]
-#[get("/tag/loader")]
+#[get("/tag/loader", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
 pub async fn get_loaders(data: web::Data<AppState>) -> Result<impl Responder, ApiError> {
     let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
     match service.get_loaders().await {
@@ -433,7 +627,7 @@ pub async fn get_loaders(data: web::Data<AppState>) -> Result<impl Responder, Ap
     tag = "Modrinth",
     description = "Get all game versions"
 )]
-#[get("/tag/game_version")]
+#[get("/tag/game_version", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
 pub async fn get_game_versions(data: web::Data<AppState>) -> Result<impl Responder, ApiError> {
     let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
     match service.get_game_versions().await {
@@ -441,3 +635,117 @@ pub async fn get_game_versions(data: web::Data<AppState>) -> Result<impl Respond
         Err(e) => Err(ApiError::from(e)),
     }
 }
+
+#[utoipa::path(
+    get,
+    path = "/modrinth/v2/tag/license",
+    responses(
+        (status = 200, description = "Licenses found", body = Vec<LicenseTag>)
+    ),
+    tag = "Modrinth",
+    description = "Get all licenses"
+)]
+#[get("/tag/license", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
+pub async fn get_licenses(data: web::Data<AppState>) -> Result<impl Responder, ApiError> {
+    let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
+    match service.get_licenses().await {
+        Ok(licenses) => Ok(web::Json(licenses)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/modrinth/v2/tag/license/{id}",
+    params(
+        ("id" = String, Path, description = "SPDX ID of the license", example = "MIT")
+    ),
+    responses(
+        (status = 200, description = "License text found", body = LicenseText)
+    ),
+    tag = "Modrinth",
+    description = "Get the text of a license by its SPDX ID"
+)]
+#[get("/tag/license/{id}", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
+pub async fn get_license_text(
+    license_id: web::Path<String>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
+    match service.get_license_text(license_id.into_inner()).await {
+        Ok(license_text) => Ok(web::Json(license_text)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/modrinth/v2/tag/donation_platform",
+    responses(
+        (status = 200, description = "Donation platforms found", body = Vec<DonationPlatform>)
+    ),
+    tag = "Modrinth",
+    description = "Get all donation platforms"
+)]
+#[get("/tag/donation_platform", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
+pub async fn get_donation_platforms(data: web::Data<AppState>) -> Result<impl Responder, ApiError> {
+    let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
+    match service.get_donation_platforms().await {
+        Ok(platforms) => Ok(web::Json(platforms)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/modrinth/v2/tag/project_type",
+    responses(
+        (status = 200, description = "Project types found", body = Vec<TagName>)
+    ),
+    tag = "Modrinth",
+    description = "Get all project types"
+)]
+#[get("/tag/project_type", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
+pub async fn get_project_types(data: web::Data<AppState>) -> Result<impl Responder, ApiError> {
+    let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
+    match service.get_project_types().await {
+        Ok(project_types) => Ok(web::Json(project_types)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/modrinth/v2/tag/side_type",
+    responses(
+        (status = 200, description = "Side types found", body = Vec<TagName>)
+    ),
+    tag = "Modrinth",
+    description = "Get all side types"
+)]
+#[get("/tag/side_type", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
+pub async fn get_side_types(data: web::Data<AppState>) -> Result<impl Responder, ApiError> {
+    let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
+    match service.get_side_types().await {
+        Ok(side_types) => Ok(web::Json(side_types)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/modrinth/v2/tag/report_type",
+    responses(
+        (status = 200, description = "Report types found", body = Vec<TagName>)
+    ),
+    tag = "Modrinth",
+    description = "Get all report types"
+)]
+#[get("/tag/report_type", wrap = "RouteCache::new(TAG_CACHE_TTL)")]
+pub async fn get_report_types(data: web::Data<AppState>) -> Result<impl Responder, ApiError> {
+    let service = ModrinthService::new(data.db.clone(), data.redis_pool.clone());
+    match service.get_report_types().await {
+        Ok(report_types) => Ok(web::Json(report_types)),
+        Err(e) => Err(ApiError::from(e)),
+    }
+}
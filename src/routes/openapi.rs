@@ -1,20 +1,37 @@
 use actix_web::{HttpResponse, Responder};
-use utoipa::OpenApi;
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
 use utoipauto::utoipauto;
 
 #[allow(unknown_lints)]
 #[utoipauto]
 #[derive(OpenApi)]
-#[openapi(info(
-    title = "MCIM API",
-    version = "1.0.0",
-    contact(
-        name = "mcmod-info-mirror",
-        url = "https://github.com/mcmod-info-mirror"
-    )
-))]
+#[openapi(
+    info(
+        title = "MCIM API",
+        version = "1.0.0",
+        contact(
+            name = "mcmod-info-mirror",
+            url = "https://github.com/mcmod-info-mirror"
+        )
+    ),
+    modifiers(&SecurityAddon)
+)]
 pub struct OpenApiDoc;
 
+// /admin 接口使用的 Bearer Token
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "admin_token",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+    }
+}
+
 pub async fn serve_openapi() -> impl Responder {
     let openapi_string = OpenApiDoc::openapi()
         .to_json()
@@ -0,0 +1,59 @@
+use actix_web::{Responder, get, web};
+
+use crate::errors::ApiError;
+use crate::models::search::requests::UnifiedSearchQuery;
+use crate::models::search::responses::UnifiedSearchResponse;
+use crate::services::search::{SearchService, normalize_query};
+use crate::utils::app::AppState;
+use crate::utils::redis_cache::{SEARCH_CACHE_TTL, cacheable_json_if, create_key};
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(search);
+}
+
+#[utoipa::path(
+    get,
+    path = "/search",
+    params(
+        ("query" = Option<String>, Query, description = "Search query", example = "sodium"),
+        ("platforms" = Option<String>, Query, description = "Comma separated platforms to search, defaults to modrinth,curseforge", example = "modrinth,curseforge"),
+        ("game_version" = Option<String>, Query, description = "Minecraft version", example = "1.20.1"),
+        ("loader" = Option<String>, Query, description = "Mod loader", example = "fabric"),
+        ("offset" = Option<i32>, Query, description = "Offset for pagination, offset + limit must not exceed 100", example = "0"),
+        ("limit" = Option<i32>, Query, description = "Limit for pagination", example = "20")
+    ),
+    responses(
+        (status = 200, description = "Search results, platforms that failed are listed in errors", body = UnifiedSearchResponse),
+        (status = 400, description = "Invalid parameters"),
+        (status = 500, description = "All platforms failed")
+    ),
+    description = "Search Modrinth projects and CurseForge mods at once, results are interleaved by downloads",
+    tag = "Search"
+)]
+#[get("/search")]
+async fn search(
+    query: web::Query<UnifiedSearchQuery>,
+    data: web::Data<AppState>,
+) -> Result<impl Responder, ApiError> {
+    // 会请求两个平台的官方 API，与各平台的搜索一样经过缓存
+    let key = create_key(
+        "GET".to_string(),
+        "/search".to_string(),
+        normalize_query(&query).map_err(ApiError::from)?,
+    );
+    let redis_pool = data.redis_pool.clone();
+    let query = query.into_inner();
+
+    // 部分平台失败的结果不缓存，避免短暂故障后长时间返回缺失的结果
+    cacheable_json_if(
+        redis_pool.clone(),
+        key,
+        SEARCH_CACHE_TTL,
+        |response: &UnifiedSearchResponse| response.errors.is_empty(),
+        move || {
+            let service = SearchService::new(data.db.clone(), redis_pool);
+            Box::pin(async move { service.search(&data, &query).await.map_err(Into::into) })
+        },
+    )
+    .await
+}
@@ -0,0 +1,129 @@
+use bson::doc;
+use chrono::Utc;
+use futures::stream::TryStreamExt;
+use mongodb::error::{Error as MongoError, ErrorKind, WriteFailure};
+use mongodb::{Client, Collection};
+use sha2::{Digest, Sha256};
+
+use crate::db::database::get_database_name;
+use crate::errors::ServiceError;
+use crate::models::admin::entities::{AdminRole, AdminToken};
+
+const ADMIN_TOKEN_COLLECTION: &str = "admin_tokens";
+const TOKEN_PREFIX: &str = "mcim_";
+const DUPLICATE_KEY: i32 = 11000;
+
+pub struct AdminTokenService {
+    pub db: Client,
+}
+
+impl AdminTokenService {
+    pub fn new(db: Client) -> Self {
+        Self { db }
+    }
+
+    fn collection(&self) -> Collection<AdminToken> {
+        self.db
+            .database(get_database_name().as_str())
+            .collection::<AdminToken>(ADMIN_TOKEN_COLLECTION)
+    }
+
+    /// 创建 token，返回明文 token 与保存的记录
+    pub async fn create_token(
+        &self,
+        name: &str,
+        role: AdminRole,
+    ) -> Result<(String, AdminToken), ServiceError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("name"),
+                reason: String::from("Token name cannot be empty"),
+            });
+        }
+        if self
+            .collection()
+            .find_one(doc! { "name": name })
+            .await?
+            .is_some()
+        {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("name"),
+                reason: format!("Token {} already exists", name),
+            });
+        }
+
+        let token = format!(
+            "{}{}",
+            TOKEN_PREFIX,
+            hex::encode(rand::random::<[u8; 32]>())
+        );
+        let record = AdminToken {
+            token_hash: hash_token(&token),
+            name: name.to_string(),
+            role,
+            created_at: Utc::now(),
+            last_used_at: None,
+        };
+        // 并发创建同名 token 时由 name 的唯一索引拒绝
+        match self.collection().insert_one(&record).await {
+            Ok(_) => {}
+            Err(e) if is_duplicate_key(&e) => {
+                return Err(ServiceError::InvalidInput {
+                    field: String::from("name"),
+                    reason: format!("Token {} already exists", name),
+                });
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        Ok((token, record))
+    }
+
+    pub async fn list_tokens(&self) -> Result<Vec<AdminToken>, ServiceError> {
+        Ok(self
+            .collection()
+            .find(doc! {})
+            .sort(doc! { "created_at": 1 })
+            .await?
+            .try_collect()
+            .await?)
+    }
+
+    pub async fn revoke_token(&self, name: &str) -> Result<(), ServiceError> {
+        let result = self.collection().delete_one(doc! { "name": name }).await?;
+        if result.deleted_count == 0 {
+            return Err(ServiceError::NotFound {
+                resource: String::from("Admin Token"),
+                detail: Some(format!("Token {} not found", name)),
+            });
+        }
+        Ok(())
+    }
+
+    /// 校验明文 token，成功时记录最近使用时间
+    pub async fn authenticate(&self, token: &str) -> Result<Option<AdminToken>, ServiceError> {
+        if !token.starts_with(TOKEN_PREFIX) {
+            return Ok(None);
+        }
+
+        Ok(self
+            .collection()
+            .find_one_and_update(
+                doc! { "_id": hash_token(token) },
+                doc! { "$set": { "last_used_at": bson::DateTime::now() } },
+            )
+            .await?)
+    }
+}
+
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+fn is_duplicate_key(e: &MongoError) -> bool {
+    matches!(
+        e.kind.as_ref(),
+        ErrorKind::Write(WriteFailure::WriteError(w)) if w.code == DUPLICATE_KEY
+    )
+}
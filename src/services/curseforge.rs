@@ -1,81 +1,54 @@
 use bson::doc;
+use bytes::Bytes;
 use futures::stream::TryStreamExt;
 use mongodb::Client as Mongo_Client;
-use redis::AsyncCommands;
-use redis::aio::MultiplexedConnection;
-use reqwest::Client;
-use std::collections::HashMap;
-use std::sync::Arc;
+use reqwest::{Client, StatusCode};
+use std::collections::{HashMap, HashSet};
 
+use crate::db::_redis::RedisPool;
 use crate::db::database::get_database_name;
+use crate::db::sync_queue::{
+    CURSEFORGE_FILEIDS, CURSEFORGE_FINGERPRINTS, CURSEFORGE_MODIDS, enqueue,
+};
 use crate::errors::ServiceError;
-use crate::models::curseforge::entities::{Category as DBCategory, File as DBFile, Mod as DBMod};
-use crate::models::curseforge::requests::SearchQuery;
+use crate::models::curseforge::entities::{
+    Category as DBCategory, File as DBFile, FileChangelog as DBFileChangelog, Game as DBGame,
+    GameVersionType as DBGameVersionType, GameVersionsByType as DBGameVersionsByType,
+    MinecraftGameVersion as DBMinecraftGameVersion,
+    MinecraftModLoaderVersion as DBMinecraftModLoaderVersion, Mod as DBMod,
+    ModDescription as DBModDescription,
+};
+use crate::models::curseforge::requests::{FolderFingerprint, ModFilesQuery, SearchQuery};
 use crate::models::curseforge::responses::*;
+use crate::utils::app::SearchMode;
+
+// 镜像只收录 Minecraft
+const MINECRAFT_GAME_ID: i32 = 432;
+// Java Edition 的版本类型 slug 形如 minecraft-1-20，按年份编号后形如 minecraft-26-1
+const JAVA_VERSION_TYPE_SLUG_PATTERN: &str = r"^minecraft-\d+(-\d+)*$";
+// 排名越小越热门，排序方向与其他字段相反
+const POPULARITY_SORT_FIELD: &str = "gamePopularityRank";
 
 pub struct CurseforgeService {
     db: Mongo_Client,
-    redis: Arc<MultiplexedConnection>,
+    redis: RedisPool,
 }
 
 impl CurseforgeService {
-    pub fn new(db: Mongo_Client, redis: Arc<MultiplexedConnection>) -> Self {
+    pub fn new(db: Mongo_Client, redis: RedisPool) -> Self {
         Self { db, redis }
     }
 
-    async fn add_modids_into_queue(&self, mod_ids: Vec<i32>) -> Result<(), ServiceError> {
-        if mod_ids.is_empty() {
-            return Ok(());
-        }
-        let mut conn = self.redis.as_ref().clone();
-        conn.sadd::<&str, &Vec<i32>, ()>("curseforge_modids", &mod_ids)
-            .await
-            .map_err(|e| -> ServiceError {
-                ServiceError::ExternalServiceError {
-                    service: "Redis".into(),
-                    message: format!("Failed to add modIds to Redis queue: {}", e),
-                }
-            })?;
-        log::debug!("Added modIds to Redis queue: {:?}", mod_ids);
-        Ok(())
+    async fn add_modids_into_queue(&self, mod_ids: Vec<i32>, source: &str) {
+        enqueue(&self.redis, CURSEFORGE_MODIDS, &mod_ids, source).await;
     }
 
-    async fn add_fileids_into_queue(&self, file_ids: Vec<i32>) -> Result<(), ServiceError> {
-        if file_ids.is_empty() {
-            return Ok(());
-        }
-        let mut conn = self.redis.as_ref().clone();
-        conn.sadd::<&str, &Vec<i32>, ()>("curseforge_fileids", &file_ids)
-            .await
-            .map_err(|e| -> ServiceError {
-                ServiceError::ExternalServiceError {
-                    service: "Redis".into(),
-                    message: format!("Failed to add fileIds to Redis queue: {}", e),
-                }
-            })?;
-        log::debug!("Added fileIds to Redis queue: {:?}", file_ids);
-        Ok(())
+    async fn add_fileids_into_queue(&self, file_ids: Vec<i32>, source: &str) {
+        enqueue(&self.redis, CURSEFORGE_FILEIDS, &file_ids, source).await;
     }
 
-    async fn add_fingerprints_into_queue(
-        &self,
-        fingerprints: Vec<i64>,
-    ) -> Result<(), ServiceError> {
-        if fingerprints.is_empty() {
-            return Ok(());
-        }
-
-        let mut conn = self.redis.as_ref().clone();
-        conn.sadd::<&str, &Vec<i64>, ()>("curseforge_fingerprints", &fingerprints)
-            .await
-            .map_err(|e| -> ServiceError {
-                ServiceError::ExternalServiceError {
-                    service: "Redis".into(),
-                    message: format!("Failed to add fingerprints to Redis queue: {}", e),
-                }
-            })?;
-        log::debug!("Added fingerprints to Redis queue: {:?}", fingerprints);
-        Ok(())
+    async fn add_fingerprints_into_queue(&self, fingerprints: Vec<i64>, source: &str) {
+        enqueue(&self.redis, CURSEFORGE_FINGERPRINTS, &fingerprints, source).await;
     }
 
     async fn check_search_result(&self, data: &serde_json::Value) -> Result<(), ServiceError> {
@@ -140,7 +113,8 @@ impl CurseforgeService {
             .collect();
 
         if !not_found_mod_ids.is_empty() {
-            self.add_modids_into_queue(not_found_mod_ids).await?;
+            self.add_modids_into_queue(not_found_mod_ids, "curseforge:search_mods")
+                .await;
         } else {
             log::trace!("All Mods have been found in the database.");
         }
@@ -155,6 +129,20 @@ impl CurseforgeService {
         curseforge_api_url: &str,
         curseforge_api_key: &str,
     ) -> Result<serde_json::Value, ServiceError> {
+        let (status, bytes) = self
+            .send_search_mods(client, query, curseforge_api_url, curseforge_api_key)
+            .await?;
+        self.parse_search_mods(status, bytes).await
+    }
+
+    // 只在网络错误时返回 Err，状态码由调用方处理
+    async fn send_search_mods(
+        &self,
+        client: &Client,
+        query: &SearchQuery,
+        curseforge_api_url: &str,
+        curseforge_api_key: &str,
+    ) -> Result<(StatusCode, Bytes), ServiceError> {
         let mut params: Vec<(&str, String)> = Vec::new();
 
         macro_rules! add_param {
@@ -170,7 +158,7 @@ impl CurseforgeService {
             };
         }
 
-        let game_id = query.game_id.unwrap_or(432);
+        let game_id = query.game_id.unwrap_or(MINECRAFT_GAME_ID);
         let index = query.index.unwrap_or(0);
         let page_size = query.page_size.unwrap_or(50);
 
@@ -205,27 +193,33 @@ impl CurseforgeService {
             })?;
 
         let status = response.status();
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| ServiceError::ExternalServiceError {
+                service: "Curseforge API".into(),
+                message: format!("Failed to read response body: {}", e),
+            })?;
+
+        Ok((status, bytes))
+    }
+
+    async fn parse_search_mods(
+        &self,
+        status: StatusCode,
+        bytes: Bytes,
+    ) -> Result<serde_json::Value, ServiceError> {
         if !status.is_success() {
             return Err(ServiceError::ExternalServiceError {
                 service: "Curseforge API".into(),
                 message: format!(
                     "Request failed with status: {}, response: {}",
                     status,
-                    response
-                        .text()
-                        .await
-                        .unwrap_or_else(|_| "No response text".to_string())
+                    String::from_utf8_lossy(&bytes)
                 ),
             });
         }
 
-        let bytes = response
-            .bytes()
-            .await
-            .map_err(|e| ServiceError::ExternalServiceError {
-                service: "Curseforge API".into(),
-                message: format!("Failed to read response body: {}", e),
-            })?;
         let search_result: serde_json::Value =
             serde_json::from_slice(&bytes).map_err(|e| ServiceError::ExternalServiceError {
                 service: "Curseforge API".into(),
@@ -245,6 +239,236 @@ impl CurseforgeService {
         Ok(search_result)
     }
 
+    pub async fn search_mods_by_mode(
+        &self,
+        client: &Client,
+        query: &SearchQuery,
+        curseforge_api_url: &str,
+        curseforge_api_key: &str,
+        mode: SearchMode,
+    ) -> Result<serde_json::Value, ServiceError> {
+        match mode {
+            SearchMode::Upstream => {
+                self.search_mods(client, query, curseforge_api_url, curseforge_api_key)
+                    .await
+            }
+            SearchMode::Local => self.search_mods_local_json(query).await,
+            SearchMode::Fallback => {
+                match self
+                    .send_search_mods(client, query, curseforge_api_url, curseforge_api_key)
+                    .await
+                {
+                    // 上游 5xx 视为不可用，回退到本地搜索
+                    Ok((status, _)) if status.is_server_error() => {
+                        log::warn!(
+                            "Curseforge upstream search returned {}, fallback to local",
+                            status
+                        );
+                        self.search_mods_local_json(query).await
+                    }
+                    // 4xx 等说明请求本身有误，直接返回给调用方
+                    Ok((status, bytes)) => self.parse_search_mods(status, bytes).await,
+                    Err(e @ ServiceError::InvalidInput { .. }) => Err(e),
+                    Err(e) => {
+                        log::warn!(
+                            "Curseforge upstream search failed, fallback to local: {}",
+                            e
+                        );
+                        self.search_mods_local_json(query).await
+                    }
+                }
+            }
+        }
+    }
+
+    async fn search_mods_local_json(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<serde_json::Value, ServiceError> {
+        let result = self.search_mods_local(query).await?;
+        serde_json::to_value(result).map_err(|e| {
+            ServiceError::UnexpectedError(format!("Failed to serialize search result: {}", e))
+        })
+    }
+
+    pub async fn search_mods_local(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<SearchResponse, ServiceError> {
+        let index = query.index.unwrap_or(0);
+        let page_size = query.page_size.unwrap_or(50);
+        if index < 0 || !(1..=50).contains(&page_size) || index + page_size > 10000 {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("index or pageSize"),
+                reason: String::from("pageSize must be in 1..=50 and index + pageSize <= 10000"),
+            });
+        }
+
+        let mut filter = doc! { "gameId": query.game_id.unwrap_or(MINECRAFT_GAME_ID) };
+
+        if let Some(class_id) = query.class_id {
+            filter.insert("classId", class_id);
+        }
+
+        // categoryIds 会覆盖 categoryId
+        if let Some(category_ids) = &query.category_ids {
+            filter.insert(
+                "categories.id",
+                doc! { "$in": parse_category_ids(category_ids)? },
+            );
+        } else if let Some(category_id) = query.category_id {
+            filter.insert("categories.id", category_id);
+        }
+
+        if let Some(author_id) = query.author_id {
+            filter.insert("authors.id", author_id);
+        }
+        if let Some(primary_author_id) = query.primary_author_id {
+            filter.insert("authors.0.id", primary_author_id);
+        }
+        if let Some(slug) = &query.slug {
+            filter.insert("slug", slug);
+        }
+
+        // 使用 name / slug / summary 上的文本索引，见 db::indexes
+        if let Some(search_filter) = &query.search_filter {
+            let search_filter = search_filter.trim();
+            if !search_filter.is_empty() {
+                filter.insert("$text", doc! { "$search": search_filter });
+            }
+        }
+
+        // gameVersion / modLoaderType / gameVersionTypeId 需要命中同一个 latestFilesIndexes 元素
+        let mut index_filter = doc! {};
+        let mut game_versions = Vec::new();
+        if let Some(game_versions_str) = &query.game_versions {
+            game_versions.extend(parse_list_param(game_versions_str));
+        } else if let Some(game_version) = &query.game_version {
+            game_versions.push(game_version.clone());
+        }
+        if !game_versions.is_empty() {
+            index_filter.insert("gameVersion", doc! { "$in": game_versions });
+        }
+
+        let mut loader_types = Vec::new();
+        if let Some(loader_types_str) = &query.mod_loader_types {
+            loader_types.extend(parse_mod_loader_types(loader_types_str)?);
+        } else if let Some(loader_type) = &query.mod_loader_type {
+            loader_types.push(parse_mod_loader_type(loader_type)?);
+        }
+        loader_types.retain(|t| *t != 0);
+        if !loader_types.is_empty() {
+            index_filter.insert("modLoader", doc! { "$in": loader_types });
+        }
+
+        if let Some(game_version_type_id) = query.game_version_type_id {
+            index_filter.insert("gameVersionTypeId", game_version_type_id);
+        }
+        if !index_filter.is_empty() {
+            filter.insert("latestFilesIndexes", doc! { "$elemMatch": index_filter });
+        }
+
+        let sort_field = match query.sort_field.as_deref() {
+            None => "downloadCount",
+            Some(field) => mod_sort_field(field)?,
+        };
+        let mut sort_order = match query.sort_order.as_deref() {
+            None | Some("desc") => -1,
+            Some("asc") => 1,
+            Some(order) => {
+                return Err(ServiceError::InvalidInput {
+                    field: String::from("sortOrder"),
+                    reason: format!("Invalid sort order {}, expected asc or desc", order),
+                });
+            }
+        };
+        // gamePopularityRank 越小越热门，desc 应返回最热门的 Mod
+        if sort_field == POPULARITY_SORT_FIELD {
+            sort_order = -sort_order;
+        }
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<DBMod>("curseforge_mods");
+
+        let pipeline = vec![
+            doc! { "$match": filter },
+            doc! {
+                "$facet": {
+                    "data": [
+                        doc! { "$sort": { sort_field: sort_order, "_id": sort_order } },
+                        doc! { "$skip": index },
+                        doc! { "$limit": page_size }
+                    ],
+                    "count": [
+                        doc! { "$count": "total" }
+                    ]
+                }
+            },
+        ];
+
+        let mut cursor =
+            collection
+                .aggregate(pipeline)
+                .await
+                .map_err(|e| ServiceError::DatabaseError {
+                    message: String::from("Failed to aggregate local search"),
+                    source: Some(e),
+                })?;
+
+        let result = cursor
+            .try_next()
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to fetch local search result"),
+                source: Some(e),
+            })?;
+
+        let (mods, total_count) = match result {
+            Some(doc) => {
+                let data_array = doc.get_array("data").map_err(|_| {
+                    ServiceError::UnexpectedError("Failed to get data array".to_string())
+                })?;
+
+                let mut mods: Vec<Mod> = Vec::new();
+                for item in data_array {
+                    if let Some(mod_doc) = item.as_document() {
+                        let mod_data: DBMod = bson::deserialize_from_document(mod_doc.clone())
+                            .map_err(|e| {
+                                ServiceError::UnexpectedError(format!(
+                                    "Failed to deserialize Mod: {}",
+                                    e
+                                ))
+                            })?;
+                        mods.push(mod_data.into());
+                    }
+                }
+
+                let total_count = doc
+                    .get_array("count")
+                    .ok()
+                    .and_then(|count| count.first())
+                    .and_then(|item| item.as_document())
+                    .and_then(|d| d.get_i32("total").ok())
+                    .unwrap_or(0);
+
+                (mods, total_count)
+            }
+            None => (Vec::new(), 0),
+        };
+
+        Ok(SearchResponse {
+            pagination: Pagination {
+                index,
+                page_size,
+                result_count: mods.len() as i32,
+                total_count,
+            },
+            data: mods,
+        })
+    }
+
     pub async fn get_mod(&self, mod_id: i32) -> Result<Option<ModResponse>, ServiceError> {
         if mod_id.is_negative() {
             return Err(ServiceError::InvalidInput {
@@ -268,7 +492,8 @@ impl CurseforgeService {
             }
             None => {
                 // 不存在则添加到队列
-                self.add_modids_into_queue(vec![mod_id]).await?;
+                self.add_modids_into_queue(vec![mod_id], "curseforge:get_mod")
+                    .await;
 
                 Err(ServiceError::NotFound {
                     resource: String::from("Mod"),
@@ -278,7 +503,11 @@ impl CurseforgeService {
         }
     }
 
-    pub async fn get_mods(&self, mod_ids: Vec<i32>) -> Result<ModsResponse, ServiceError> {
+    pub async fn get_mods(
+        &self,
+        mod_ids: Vec<i32>,
+        filter_pc_only: Option<bool>,
+    ) -> Result<ModsResponse, ServiceError> {
         if mod_ids.is_empty() {
             return Err(ServiceError::InvalidInput {
                 field: String::from("mod_ids"),
@@ -328,17 +557,82 @@ impl CurseforgeService {
                 "modIds not found in database: {:?}, adding to queue for processing.",
                 not_found_mod_ids
             );
-            self.add_modids_into_queue(not_found_mod_ids).await?;
+            self.add_modids_into_queue(not_found_mod_ids, "curseforge:get_mods")
+                .await;
         } else {
             log::trace!("All Mods have been found in the database.");
         }
 
+        // 过滤后可能为空，此时返回 { "data": [] } 而不是 404
+        if filter_pc_only.unwrap_or(false) {
+            let pc_version_type_ids = self.get_pc_version_type_ids().await?;
+            if pc_version_type_ids.is_empty() {
+                log::warn!(
+                    "No Java Edition version types found for gameId {}, skip filterPcOnly",
+                    MINECRAFT_GAME_ID
+                );
+            } else {
+                mods.retain(|m| Self::is_pc_mod(m, &pc_version_type_ids));
+            }
+        }
+
         let response_mods = mods.into_iter().map(|m| m.into()).collect();
         Ok(ModsResponse {
             data: response_mods,
         })
     }
 
+    // PC 版 (Java Edition) 的 gameVersionTypeId 集合
+    // 只取 `Minecraft <版本号>` 版本类型，Modloader、Environment、Bedrock 等类型不计入
+    async fn get_pc_version_type_ids(&self) -> Result<HashSet<i32>, ServiceError> {
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<DBGameVersionType>("curseforge_version_types");
+
+        let cursor = collection
+            .find(doc! {
+                "gameId": MINECRAFT_GAME_ID,
+                "slug": { "$regex": JAVA_VERSION_TYPE_SLUG_PATTERN }
+            })
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to fetch version types from database"),
+                source: Some(e),
+            })?;
+
+        let version_types: Vec<DBGameVersionType> =
+            cursor
+                .try_collect()
+                .await
+                .map_err(|e| ServiceError::DatabaseError {
+                    message: String::from("Failed to fetch version types from database"),
+                    source: Some(e),
+                })?;
+
+        Ok(version_types.into_iter().map(|t| t.id).collect())
+    }
+
+    // 只要 latestFilesIndexes 或 latestFiles.sortableGameVersions 中有一个 PC 版本类型即视为 PC Mod
+    fn is_pc_mod(mod_data: &DBMod, pc_version_type_ids: &HashSet<i32>) -> bool {
+        let from_indexes = mod_data
+            .latest_files_indexes
+            .iter()
+            .flatten()
+            .filter_map(|index| index.game_version_type_id);
+
+        let from_files = mod_data
+            .latest_files
+            .iter()
+            .flatten()
+            .flat_map(|file| file.sortable_game_versions.iter().flatten())
+            .filter_map(|version| version.game_version_type_id);
+
+        from_indexes
+            .chain(from_files)
+            .any(|type_id| pc_version_type_ids.contains(&type_id))
+    }
+
     pub async fn get_file(&self, file_id: i32) -> Result<FileResponse, ServiceError> {
         if file_id.is_negative() {
             return Err(ServiceError::InvalidInput {
@@ -366,7 +660,8 @@ impl CurseforgeService {
                 Ok(response)
             }
             None => {
-                self.add_fileids_into_queue(vec![file_id]).await?;
+                self.add_fileids_into_queue(vec![file_id], "curseforge:get_file")
+                    .await;
                 Err(ServiceError::NotFound {
                     resource: String::from("File"),
                     detail: Some(format!("File with fileId {} not found", file_id)),
@@ -412,7 +707,8 @@ impl CurseforgeService {
             .cloned()
             .collect();
         if !not_found_file_ids.is_empty() {
-            self.add_fileids_into_queue(not_found_file_ids).await?;
+            self.add_fileids_into_queue(not_found_file_ids, "curseforge:get_files")
+                .await;
         } else {
             log::trace!("All Files have been found in the database.");
         }
@@ -437,10 +733,7 @@ impl CurseforgeService {
     pub async fn get_mod_files(
         &self,
         mod_id: i32,
-        game_version: Option<String>,
-        mod_loader_type: Option<i32>,
-        index: Option<i32>,
-        page_size: Option<i32>,
+        query: ModFilesQuery,
     ) -> Result<ModFilesResponse, ServiceError> {
         if mod_id.is_negative() {
             return Err(ServiceError::InvalidInput {
@@ -455,42 +748,83 @@ impl CurseforgeService {
             .collection::<DBFile>("curseforge_files");
 
         let mut filter = doc! { "modId": mod_id };
-        let mut game_version_filters = Vec::new();
-
-        if let Some(version) = game_version {
-            game_version_filters.push(version);
-        }
-
-        if let Some(loader_type) = mod_loader_type {
-            let loader_type_str = match loader_type {
-                1 => "Forge",
-                2 => "Cauldron",
-                3 => "LiteLoader",
-                4 => "Fabric",
-                5 => "Quilt",
-                6 => "NeoForge",
-                _ => {
-                    return Err(ServiceError::UnexpectedError(String::from(
-                        "Invalid mod loader type",
-                    )));
-                }
-            };
-            game_version_filters.push(loader_type_str.into());
+        let mut game_versions_filter = doc! {};
+
+        if let Some(version) = query.game_version {
+            game_versions_filter.insert("$all", vec![version]);
         }
 
-        if !game_version_filters.is_empty() {
-            filter.insert("gameVersions", doc! { "$all": game_version_filters });
+        // modLoaderType 与 modLoaderTypes 合并，文件满足任一 loader 即可
+        let mut loader_types = Vec::new();
+        if let Some(loader_type) = query.mod_loader_type {
+            loader_types.push(loader_type);
+        }
+        if let Some(loader_types_str) = query.mod_loader_types {
+            loader_types.extend(parse_mod_loader_types(&loader_types_str)?);
+        }
+        let mut loader_names = Vec::new();
+        for loader_type in loader_types {
+            // 0 为 Any，不过滤
+            if loader_type != 0 {
+                loader_names.push(mod_loader_type_name(loader_type)?);
+            }
+        }
+        if !loader_names.is_empty() {
+            game_versions_filter.insert("$in", loader_names);
+        }
+
+        if !game_versions_filter.is_empty() {
+            filter.insert("gameVersions", game_versions_filter);
+        }
+
+        if let Some(game_version_type_id) = query.game_version_type_id {
+            filter.insert(
+                "sortableGameVersions.gameVersionTypeId",
+                game_version_type_id,
+            );
+        }
+
+        if let Some(release_type) = query.release_type {
+            if !(1..=3).contains(&release_type) {
+                return Err(ServiceError::InvalidInput {
+                    field: String::from("releaseType"),
+                    reason: format!(
+                        "Invalid release type {}, expected 1 (Release), 2 (Beta) or 3 (Alpha)",
+                        release_type
+                    ),
+                });
+            }
+            filter.insert("releaseType", release_type);
+        }
+
+        let sort_field = match query.sort_field.as_deref() {
+            None => "fileDate",
+            Some(field) => file_sort_field(field)?,
+        };
+        let mut sort_order = match query.sort_order.as_deref() {
+            None | Some("desc") => -1,
+            Some("asc") => 1,
+            Some(order) => {
+                return Err(ServiceError::InvalidInput {
+                    field: String::from("sortOrder"),
+                    reason: format!("Invalid sort order {}, expected asc or desc", order),
+                });
+            }
+        };
+        // gamePopularityRank 越小越热门，desc 应返回最热门的 Mod
+        if sort_field == POPULARITY_SORT_FIELD {
+            sort_order = -sort_order;
         }
 
-        let index = index.unwrap_or(0);
-        let page_size = page_size.unwrap_or(50);
+        let index = query.index.unwrap_or(0);
+        let page_size = query.page_size.unwrap_or(50);
 
         let pipeline = vec![
             doc! { "$match": filter },
             doc! {
                 "$facet": {
                     "data": [
-                        doc! { "$sort": { "fileDate": -1 } },
+                        doc! { "$sort": { sort_field: sort_order, "_id": sort_order } },
                         doc! { "$skip": index },
                         doc! { "$limit": page_size }
                     ],
@@ -559,7 +893,7 @@ impl CurseforgeService {
 
         // 有筛选条件很容易为空，不能当作 Mod 不存在
         // if total_count == 0 {
-        //     self.add_modids_into_queue(vec![mod_id]).await?;
+        //     self.add_modids_into_queue(vec![mod_id]).await;
         // }
 
         let result_count = files.len() as i32;
@@ -594,53 +928,320 @@ impl CurseforgeService {
         })
     }
 
-    pub async fn get_fingerprints(
+    pub async fn get_mod_description(
         &self,
-        fingerprints: Vec<i64>,
-        game_id: Option<i32>,
-    ) -> Result<FingerprintResponse, ServiceError> {
-        if fingerprints.is_empty() {
+        mod_id: i32,
+    ) -> Result<ModDescriptionResponse, ServiceError> {
+        if mod_id.is_negative() {
             return Err(ServiceError::InvalidInput {
-                field: String::from("fingerprints"),
-                reason: String::from("Fingerprints cannot be empty"),
+                field: String::from("mod_id"),
+                reason: String::from("ModId cannot be negative"),
             });
         }
 
-        let installed_fingerprints = fingerprints.clone();
-
         let collection = self
             .db
             .database(get_database_name().as_str())
-            .collection::<DBFile>("curseforge_files");
-
-        // 可选 game_id 参数用于过滤
-        let mut filter = doc! { "fileFingerprint": { "$in": &fingerprints } };
-        if let Some(game_id) = game_id {
-            filter.insert("gameId", game_id);
-        }
-
-        let mut cursor = collection.find(filter).await?;
-        let mut file_results: Vec<File> = Vec::new();
+            .collection::<DBModDescription>("curseforge_mod_descriptions");
 
-        while let Ok(Some(doc)) = cursor
-            .try_next()
+        match collection
+            .find_one(doc! { "_id": mod_id })
             .await
             .map_err(|e| ServiceError::DatabaseError {
-                message: String::from("Failed to fetch fingerprints from database"),
+                message: "Failed to fetch mod description by ID".to_string(),
                 source: Some(e),
-            })
-        {
-            file_results.push(doc.into());
-        }
+            })? {
+            Some(description) => Ok(ModDescriptionResponse {
+                data: description.description,
+            }),
+            None => {
+                // 不存在则添加到队列
+                self.add_modids_into_queue(vec![mod_id], "curseforge:get_mod_description")
+                    .await;
 
-        let exact_fingerprints = file_results
-            .iter()
-            .filter_map(|f| f.file_fingerprint)
-            .collect();
+                Err(ServiceError::NotFound {
+                    resource: String::from("Mod Description"),
+                    detail: Some(format!("Description of modId {} not found", mod_id)),
+                })
+            }
+        }
+    }
 
-        // 查询 Mod，获取 latestFiles
-        let mod_ids = file_results.iter().map(|f| f.mod_id).collect::<Vec<_>>();
-        let mut mod_latest_files_results: HashMap<i32, Vec<FileInfo>> = HashMap::new();
+    pub async fn get_file_changelog(
+        &self,
+        mod_id: i32,
+        file_id: i32,
+    ) -> Result<FileChangelogResponse, ServiceError> {
+        if mod_id.is_negative() || file_id.is_negative() {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("mod_id or file_id"),
+                reason: String::from("ModId and FileId cannot be negative"),
+            });
+        }
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<DBFileChangelog>("curseforge_file_changelogs");
+
+        match collection
+            .find_one(doc! { "_id": file_id })
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: "Failed to fetch file changelog by ID".to_string(),
+                source: Some(e),
+            })? {
+            Some(changelog) => Ok(FileChangelogResponse {
+                data: changelog.changelog,
+            }),
+            None => {
+                self.add_fileids_into_queue(vec![file_id], "curseforge:get_file_changelog")
+                    .await;
+
+                Err(ServiceError::NotFound {
+                    resource: String::from("File Changelog"),
+                    detail: Some(format!("Changelog of fileId {} not found", file_id)),
+                })
+            }
+        }
+    }
+
+    pub async fn get_fingerprints(
+        &self,
+        fingerprints: Vec<i64>,
+        game_id: Option<i32>,
+    ) -> Result<FingerprintResponse, ServiceError> {
+        if fingerprints.is_empty() {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("fingerprints"),
+                reason: String::from("Fingerprints cannot be empty"),
+            });
+        }
+
+        let installed_fingerprints = fingerprints.clone();
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<DBFile>("curseforge_files");
+
+        // 可选 game_id 参数用于过滤
+        let mut filter = doc! { "fileFingerprint": { "$in": &fingerprints } };
+        if let Some(game_id) = game_id {
+            filter.insert("gameId", game_id);
+        }
+
+        let mut cursor = collection.find(filter).await?;
+        let mut file_results: Vec<File> = Vec::new();
+
+        while let Ok(Some(doc)) = cursor
+            .try_next()
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to fetch fingerprints from database"),
+                source: Some(e),
+            })
+        {
+            file_results.push(doc.into());
+        }
+
+        let exact_fingerprints = file_results
+            .iter()
+            .filter_map(|f| f.file_fingerprint)
+            .collect();
+
+        let unmatched_fingerprints: Vec<i64> = fingerprints
+            .into_iter()
+            .filter(|f| {
+                !file_results
+                    .iter()
+                    .any(|file| file.file_fingerprint == Some(*f))
+            })
+            .collect();
+
+        // 未精确匹配的 fingerprint 再按 File.modules 中的 fingerprint 做部分匹配
+        // 在内存中排除已精确匹配的文件，保证查询只走 modules.fingerprint 索引
+        let exact_file_ids: HashSet<i32> = file_results.iter().map(|f| f.id).collect();
+        let partial_results: Vec<File> = self
+            .find_files_by_module_fingerprints(&unmatched_fingerprints, game_id)
+            .await?
+            .into_iter()
+            .filter(|f| !exact_file_ids.contains(&f.id))
+            .collect();
+
+        let mut partial_match_fingerprints: HashMap<i64, Vec<i64>> = HashMap::new();
+        for file in &partial_results {
+            if let Some(file_fingerprint) = file.file_fingerprint {
+                partial_match_fingerprints.insert(
+                    file_fingerprint,
+                    Self::matched_module_fingerprints(file, &unmatched_fingerprints),
+                );
+            }
+        }
+
+        // 查询 Mod，获取 latestFiles
+        let mod_ids = file_results
+            .iter()
+            .chain(partial_results.iter())
+            .map(|f| f.mod_id)
+            .collect::<Vec<_>>();
+        let mod_latest_files_results = self.get_mods_latest_files(&mod_ids).await?;
+
+        // 将 id 设置为 fingerprint.file.modId
+        // https://github.com/Meloong-Git/PCL/issues/6656
+        let to_fingerprint = |f: File| Fingerprint {
+            id: f.mod_id,
+            latest_files: mod_latest_files_results
+                .get(&f.mod_id)
+                .cloned()
+                .unwrap_or_default(),
+            file: f,
+        };
+        let exact_matches = file_results.into_iter().map(to_fingerprint).collect();
+        let partial_matches = partial_results.into_iter().map(to_fingerprint).collect();
+
+        if unmatched_fingerprints.is_empty() {
+            log::trace!("All fingerprints have been found in the database.");
+        } else {
+            self.add_fingerprints_into_queue(
+                unmatched_fingerprints.clone(),
+                "curseforge:get_fingerprints",
+            )
+            .await;
+            log::debug!(
+                "Unmatched fingerprints found: {:?}, added to Redis queue for processing.",
+                unmatched_fingerprints
+            );
+        }
+
+        let response = FingerprintResponse {
+            data: FingerprintResult {
+                is_cache_built: true, // 默认值，没见过 false
+                exact_matches,
+                exact_fingerprints,
+                installed_fingerprints,
+                unmatched_fingerprints: Some(unmatched_fingerprints),
+                partial_matches,
+                partial_match_fingerprints,
+            },
+        };
+
+        Ok(response)
+    }
+
+    pub async fn get_fuzzy_fingerprints(
+        &self,
+        folders: Vec<FolderFingerprint>,
+        game_id: Option<i32>,
+    ) -> Result<FuzzyFingerprintResponse, ServiceError> {
+        if folders.is_empty() {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("fingerprints"),
+                reason: String::from("Fingerprints cannot be empty"),
+            });
+        }
+
+        let all_fingerprints: Vec<i64> = folders
+            .iter()
+            .flat_map(|folder| folder.fingerprints.iter().copied())
+            .collect();
+
+        let files = self
+            .find_files_by_module_fingerprints(&all_fingerprints, game_id)
+            .await?;
+
+        let mod_ids = files.iter().map(|f| f.mod_id).collect::<Vec<_>>();
+        let mod_latest_files_results = self.get_mods_latest_files(&mod_ids).await?;
+
+        // 每个文件夹单独匹配，按命中的 fingerprint 数量降序
+        let mut fuzzy_matches = Vec::new();
+        for folder in &folders {
+            let mut folder_matches: Vec<FuzzyFingerprintMatch> = files
+                .iter()
+                .filter_map(|file| {
+                    let matched = Self::matched_module_fingerprints(file, &folder.fingerprints);
+                    if matched.is_empty() {
+                        return None;
+                    }
+                    Some(FuzzyFingerprintMatch {
+                        id: file.mod_id,
+                        file: file.clone(),
+                        latest_files: mod_latest_files_results
+                            .get(&file.mod_id)
+                            .cloned()
+                            .unwrap_or_default(),
+                        fingerprints: matched,
+                    })
+                })
+                .collect();
+            folder_matches.sort_by_key(|m| std::cmp::Reverse(m.fingerprints.len()));
+            fuzzy_matches.extend(folder_matches);
+        }
+
+        Ok(FuzzyFingerprintResponse {
+            data: FuzzyFingerprintResult { fuzzy_matches },
+        })
+    }
+
+    // 依赖 curseforge_files 上 modules.fingerprint 的索引，见 README
+    async fn find_files_by_module_fingerprints(
+        &self,
+        fingerprints: &[i64],
+        game_id: Option<i32>,
+    ) -> Result<Vec<File>, ServiceError> {
+        if fingerprints.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<DBFile>("curseforge_files");
+
+        let mut filter = doc! {
+            "modules.fingerprint": { "$in": fingerprints },
+        };
+        if let Some(game_id) = game_id {
+            filter.insert("gameId", game_id);
+        }
+
+        let cursor = collection
+            .find(filter)
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to fetch files by module fingerprints"),
+                source: Some(e),
+            })?;
+
+        let files: Vec<DBFile> =
+            cursor
+                .try_collect()
+                .await
+                .map_err(|e| ServiceError::DatabaseError {
+                    message: String::from("Failed to fetch files by module fingerprints"),
+                    source: Some(e),
+                })?;
+
+        Ok(files.into_iter().map(|f| f.into()).collect())
+    }
+
+    fn matched_module_fingerprints(file: &File, fingerprints: &[i64]) -> Vec<i64> {
+        file.modules
+            .iter()
+            .flatten()
+            .filter_map(|module| module.fingerprint)
+            .filter(|fingerprint| fingerprints.contains(fingerprint))
+            .collect()
+    }
+
+    async fn get_mods_latest_files(
+        &self,
+        mod_ids: &[i32],
+    ) -> Result<HashMap<i32, Vec<FileInfo>>, ServiceError> {
+        let mut mod_latest_files_results: HashMap<i32, Vec<FileInfo>> = HashMap::new();
+        if mod_ids.is_empty() {
+            return Ok(mod_latest_files_results);
+        }
 
         let collection = self
             .db
@@ -648,7 +1249,7 @@ impl CurseforgeService {
             .collection::<bson::Document>("curseforge_mods");
 
         let mut cursor = collection
-            .find(doc! { "_id": { "$in": &mod_ids } })
+            .find(doc! { "_id": { "$in": mod_ids } })
             .projection(doc! { "_id": 1, "latestFiles": 1 })
             .await?;
 
@@ -699,53 +1300,7 @@ impl CurseforgeService {
             mod_latest_files_results.insert(mod_id, latest_files);
         }
 
-        let unmatched_fingerprints: Vec<i64> = fingerprints
-            .into_iter()
-            .filter(|f| {
-                !file_results
-                    .iter()
-                    .any(|file| file.file_fingerprint == Some(*f))
-            })
-            .collect();
-
-        // 将 id 设置为 fingerprint.file.modId
-        // https://github.com/Meloong-Git/PCL/issues/6656
-        let exact_matches = file_results
-            .into_iter()
-            .map(|f| Fingerprint {
-                id: f.mod_id,
-                latest_files: mod_latest_files_results
-                    .get(&f.mod_id)
-                    .cloned()
-                    .unwrap_or_default(),
-                file: f,
-            })
-            .collect();
-
-        if unmatched_fingerprints.is_empty() {
-            log::trace!("All fingerprints have been found in the database.");
-        } else {
-            self.add_fingerprints_into_queue(unmatched_fingerprints.clone())
-                .await?;
-            log::debug!(
-                "Unmatched fingerprints found: {:?}, added to Redis queue for processing.",
-                unmatched_fingerprints
-            );
-        }
-
-        let response = FingerprintResponse {
-            data: FingerprintResult {
-                is_cache_built: true, // 默认值，没见过 false
-                exact_matches,
-                exact_fingerprints,
-                installed_fingerprints,
-                unmatched_fingerprints: Some(unmatched_fingerprints),
-                partial_matches: Vec::new(), // 暂时不处理 partialMatches，不知道干嘛的
-                partial_match_fingerprints: HashMap::new(), // 暂时不处理 partialMatchFingerprints，不知道干嘛的
-            },
-        };
-
-        Ok(response)
+        Ok(mod_latest_files_results)
     }
 
     pub async fn get_categories(
@@ -805,4 +1360,421 @@ impl CurseforgeService {
             data: categories.into_iter().map(|c| c.into()).collect(),
         })
     }
+
+    fn check_game_id(game_id: i32) -> Result<(), ServiceError> {
+        if game_id != MINECRAFT_GAME_ID {
+            return Err(ServiceError::NotFound {
+                resource: String::from("Game"),
+                detail: Some(format!(
+                    "Game with ID {} not found, only gameId {} is mirrored",
+                    game_id, MINECRAFT_GAME_ID
+                )),
+            });
+        }
+        Ok(())
+    }
+
+    pub async fn get_games(
+        &self,
+        index: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<GamesResponse, ServiceError> {
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<DBGame>("curseforge_games");
+
+        let filter = doc! { "_id": MINECRAFT_GAME_ID };
+        let index = index.unwrap_or(0).max(0);
+        let page_size = page_size.unwrap_or(50).clamp(1, 50);
+
+        let total_count = collection
+            .count_documents(filter.clone())
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to count games"),
+                source: Some(e),
+            })?;
+
+        let cursor = collection
+            .find(filter)
+            .skip(index as u64)
+            .limit(page_size as i64)
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to fetch games from database"),
+                source: Some(e),
+            })?;
+
+        let games: Vec<DBGame> =
+            cursor
+                .try_collect()
+                .await
+                .map_err(|e| ServiceError::DatabaseError {
+                    message: String::from("Failed to fetch games from database"),
+                    source: Some(e),
+                })?;
+
+        Ok(GamesResponse {
+            pagination: Pagination {
+                index,
+                page_size,
+                result_count: games.len() as i32,
+                total_count: total_count as i32,
+            },
+            data: games.into_iter().map(|g| g.into()).collect(),
+        })
+    }
+
+    pub async fn get_game(&self, game_id: i32) -> Result<GameResponse, ServiceError> {
+        Self::check_game_id(game_id)?;
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<DBGame>("curseforge_games");
+
+        match collection.find_one(doc! { "_id": game_id }).await? {
+            Some(game) => Ok(GameResponse { data: game.into() }),
+            None => Err(ServiceError::NotFound {
+                resource: String::from("Game"),
+                detail: Some(format!("Game with ID {} not found", game_id)),
+            }),
+        }
+    }
+
+    async fn find_game_versions(
+        &self,
+        game_id: i32,
+    ) -> Result<Vec<DBGameVersionsByType>, ServiceError> {
+        Self::check_game_id(game_id)?;
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<DBGameVersionsByType>("curseforge_game_versions");
+
+        let cursor = collection
+            .find(doc! { "gameId": game_id })
+            .sort(doc! { "_id": 1 })
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to fetch game versions from database"),
+                source: Some(e),
+            })?;
+
+        let versions: Vec<DBGameVersionsByType> =
+            cursor
+                .try_collect()
+                .await
+                .map_err(|e| ServiceError::DatabaseError {
+                    message: String::from("Failed to fetch game versions from database"),
+                    source: Some(e),
+                })?;
+
+        Ok(versions)
+    }
+
+    pub async fn get_game_versions(
+        &self,
+        game_id: i32,
+    ) -> Result<GameVersionsResponse, ServiceError> {
+        let versions = self.find_game_versions(game_id).await?;
+
+        Ok(GameVersionsResponse {
+            data: versions.into_iter().map(|v| v.into()).collect(),
+        })
+    }
+
+    pub async fn get_game_versions_v2(
+        &self,
+        game_id: i32,
+    ) -> Result<GameVersionsV2Response, ServiceError> {
+        let versions = self.find_game_versions(game_id).await?;
+
+        Ok(GameVersionsV2Response {
+            data: versions.into_iter().map(|v| v.into()).collect(),
+        })
+    }
+
+    pub async fn get_game_version_types(
+        &self,
+        game_id: i32,
+    ) -> Result<GameVersionTypesResponse, ServiceError> {
+        Self::check_game_id(game_id)?;
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<DBGameVersionType>("curseforge_version_types");
+
+        let cursor = collection
+            .find(doc! { "gameId": game_id })
+            .sort(doc! { "_id": 1 })
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to fetch version types from database"),
+                source: Some(e),
+            })?;
+
+        let version_types: Vec<DBGameVersionType> =
+            cursor
+                .try_collect()
+                .await
+                .map_err(|e| ServiceError::DatabaseError {
+                    message: String::from("Failed to fetch version types from database"),
+                    source: Some(e),
+                })?;
+
+        Ok(GameVersionTypesResponse {
+            data: version_types.into_iter().map(|t| t.into()).collect(),
+        })
+    }
+
+    pub async fn get_minecraft_versions(
+        &self,
+        sort_descending: Option<bool>,
+    ) -> Result<MinecraftGameVersionsResponse, ServiceError> {
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<DBMinecraftGameVersion>("curseforge_minecraft_versions");
+
+        let order = if sort_descending.unwrap_or(false) {
+            -1
+        } else {
+            1
+        };
+
+        let cursor = collection
+            .find(doc! {})
+            .sort(doc! { "gameVersionId": order })
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to fetch minecraft versions from database"),
+                source: Some(e),
+            })?;
+
+        let versions: Vec<DBMinecraftGameVersion> =
+            cursor
+                .try_collect()
+                .await
+                .map_err(|e| ServiceError::DatabaseError {
+                    message: String::from("Failed to fetch minecraft versions from database"),
+                    source: Some(e),
+                })?;
+
+        Ok(MinecraftGameVersionsResponse {
+            data: versions.into_iter().map(|v| v.into()).collect(),
+        })
+    }
+
+    pub async fn get_minecraft_version(
+        &self,
+        game_version: String,
+    ) -> Result<MinecraftGameVersionResponse, ServiceError> {
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<DBMinecraftGameVersion>("curseforge_minecraft_versions");
+
+        match collection
+            .find_one(doc! { "versionString": &game_version })
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to fetch minecraft version"),
+                source: Some(e),
+            })? {
+            Some(version) => Ok(MinecraftGameVersionResponse {
+                data: version.into(),
+            }),
+            None => Err(ServiceError::NotFound {
+                resource: String::from("Minecraft Version"),
+                detail: Some(format!("Minecraft version {} not found", game_version)),
+            }),
+        }
+    }
+
+    pub async fn get_minecraft_modloaders(
+        &self,
+        version: Option<String>,
+        include_all: Option<bool>,
+    ) -> Result<MinecraftModLoaderIndexResponse, ServiceError> {
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<DBMinecraftModLoaderVersion>("curseforge_minecraft_modloaders");
+
+        let mut filter = doc! {};
+        if let Some(version) = version {
+            filter.insert("minecraftVersion", version);
+        }
+        // 官方默认只返回 latest / recommended 的版本
+        if !include_all.unwrap_or(false) {
+            filter.insert(
+                "$or",
+                vec![doc! { "latest": true }, doc! { "recommended": true }],
+            );
+        }
+
+        let cursor = collection
+            .find(filter)
+            .sort(doc! { "dateModified": -1 })
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to fetch minecraft modloaders from database"),
+                source: Some(e),
+            })?;
+
+        let modloaders: Vec<DBMinecraftModLoaderVersion> =
+            cursor
+                .try_collect()
+                .await
+                .map_err(|e| ServiceError::DatabaseError {
+                    message: String::from("Failed to fetch minecraft modloaders from database"),
+                    source: Some(e),
+                })?;
+
+        Ok(MinecraftModLoaderIndexResponse {
+            data: modloaders.into_iter().map(|m| m.into()).collect(),
+        })
+    }
+
+    pub async fn get_minecraft_modloader(
+        &self,
+        mod_loader_name: String,
+    ) -> Result<MinecraftModLoaderVersionResponse, ServiceError> {
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<DBMinecraftModLoaderVersion>("curseforge_minecraft_modloaders");
+
+        match collection
+            .find_one(doc! { "name": &mod_loader_name })
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to fetch minecraft modloader"),
+                source: Some(e),
+            })? {
+            Some(modloader) => Ok(MinecraftModLoaderVersionResponse {
+                data: modloader.into(),
+            }),
+            None => Err(ServiceError::NotFound {
+                resource: String::from("Minecraft ModLoader"),
+                detail: Some(format!("ModLoader {} not found", mod_loader_name)),
+            }),
+        }
+    }
+}
+
+pub(crate) fn mod_loader_type_name(loader_type: i32) -> Result<&'static str, ServiceError> {
+    match loader_type {
+        1 => Ok("Forge"),
+        2 => Ok("Cauldron"),
+        3 => Ok("LiteLoader"),
+        4 => Ok("Fabric"),
+        5 => Ok("Quilt"),
+        6 => Ok("NeoForge"),
+        _ => Err(ServiceError::InvalidInput {
+            field: String::from("modLoaderType"),
+            reason: format!("Unknown mod loader type {}", loader_type),
+        }),
+    }
+}
+
+// 兼容 "a,b"、"[a,b]" 与 "[\"a\",\"b\"]" 几种写法
+fn parse_list_param(value: &str) -> Vec<String> {
+    value
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|s| s.trim().trim_matches('"').trim())
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+// 同时接受数字和名称，例如 "1,4" 或 "[Forge, Fabric]"
+pub(crate) fn parse_mod_loader_type(loader_type: &str) -> Result<i32, ServiceError> {
+    if let Ok(value) = loader_type.parse::<i32>() {
+        // 与下方名称覆盖的范围一致
+        if !(0..=6).contains(&value) {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("modLoaderTypes"),
+                reason: format!("Unknown mod loader type {}", loader_type),
+            });
+        }
+        return Ok(value);
+    }
+    match loader_type.to_lowercase().as_str() {
+        "any" => Ok(0),
+        "forge" => Ok(1),
+        "cauldron" => Ok(2),
+        "liteloader" => Ok(3),
+        "fabric" => Ok(4),
+        "quilt" => Ok(5),
+        "neoforge" => Ok(6),
+        _ => Err(ServiceError::InvalidInput {
+            field: String::from("modLoaderTypes"),
+            reason: format!("Unknown mod loader type {}", loader_type),
+        }),
+    }
+}
+
+fn parse_mod_loader_types(loader_types: &str) -> Result<Vec<i32>, ServiceError> {
+    parse_list_param(loader_types)
+        .iter()
+        .map(|s| parse_mod_loader_type(s))
+        .collect()
+}
+
+fn parse_category_ids(category_ids: &str) -> Result<Vec<i32>, ServiceError> {
+    parse_list_param(category_ids)
+        .iter()
+        .map(|s| {
+            s.parse::<i32>().map_err(|_| ServiceError::InvalidInput {
+                field: String::from("categoryIds"),
+                reason: format!("Invalid category id {}", s),
+            })
+        })
+        .collect()
+}
+
+// 对应官方 ModsSearchSortField
+fn mod_sort_field(sort_field: &str) -> Result<&'static str, ServiceError> {
+    match sort_field {
+        "1" | "Featured" => Ok("isFeatured"),
+        "2" | "Popularity" => Ok(POPULARITY_SORT_FIELD),
+        "3" | "LastUpdated" => Ok("dateModified"),
+        "4" | "Name" => Ok("name"),
+        "5" | "Author" => Ok("authors.0.name"),
+        "6" | "TotalDownloads" => Ok("downloadCount"),
+        "7" | "Category" => Ok("primaryCategoryId"),
+        "8" | "GameVersion" => Ok("latestFilesIndexes.0.gameVersion"),
+        "11" | "ReleasedDate" => Ok("dateReleased"),
+        "12" | "Rating" => Ok("rating"),
+        _ => Err(ServiceError::InvalidInput {
+            field: String::from("sortField"),
+            reason: format!("Unsupported sort field {}", sort_field),
+        }),
+    }
+}
+
+fn file_sort_field(sort_field: &str) -> Result<&'static str, ServiceError> {
+    match sort_field {
+        "fileDate" => Ok("fileDate"),
+        "fileName" => Ok("fileName"),
+        "displayName" => Ok("displayName"),
+        "downloadCount" => Ok("downloadCount"),
+        "fileLength" => Ok("fileLength"),
+        "releaseType" => Ok("releaseType"),
+        _ => Err(ServiceError::InvalidInput {
+            field: String::from("sortField"),
+            reason: format!(
+                "Invalid sort field {}, expected one of fileDate, fileName, displayName, downloadCount, fileLength, releaseType",
+                sort_field
+            ),
+        }),
+    }
 }
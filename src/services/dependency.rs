@@ -0,0 +1,425 @@
+use bson::doc;
+use futures::stream::TryStreamExt;
+use mongodb::Client;
+use std::collections::{HashMap, VecDeque};
+
+use crate::db::database::get_database_name;
+use crate::errors::ServiceError;
+use crate::models::curseforge::entities::File as CurseforgeFile;
+use crate::models::dependency::responses::{
+    DependencyConflict, DependencyResolveResponse, IncompatibleDependency, ResolvedDependency,
+    UnresolvedDependency,
+};
+use crate::models::modrinth::entities::Version as ModrinthVersion;
+use crate::services::curseforge::mod_loader_type_name;
+
+// 防止循环或异常数据导致解析失控
+const MAX_RESOLVED_DEPENDENCIES: usize = 512;
+const MAX_ROOT_VERSIONS: usize = 100;
+
+// Curseforge FileRelationType
+const CURSEFORGE_REQUIRED_DEPENDENCY: i32 = 3;
+const CURSEFORGE_INCOMPATIBLE: i32 = 5;
+
+enum EdgeKind {
+    Required,
+    Incompatible,
+}
+
+struct DependencyEdge {
+    project_id: Option<String>,
+    version_id: Option<String>,
+    kind: EdgeKind,
+}
+
+struct DependencyNode {
+    project_id: String,
+    version_id: String,
+    filename: Option<String>,
+    url: Option<String>,
+    dependencies: Vec<DependencyEdge>,
+}
+
+trait DependencySource {
+    async fn get_nodes(&self, version_ids: &[String]) -> Result<Vec<DependencyNode>, ServiceError>;
+
+    // 返回该项目满足 loader 与游戏版本的最新版本
+    async fn find_latest(&self, project_id: &str) -> Result<Option<DependencyNode>, ServiceError>;
+}
+
+struct ModrinthSource<'a> {
+    db: &'a Client,
+    loader: Option<String>,
+    game_version: Option<String>,
+}
+
+impl ModrinthSource<'_> {
+    fn collection(&self) -> mongodb::Collection<ModrinthVersion> {
+        self.db
+            .database(get_database_name().as_str())
+            .collection::<ModrinthVersion>("modrinth_versions")
+    }
+}
+
+impl From<ModrinthVersion> for DependencyNode {
+    fn from(version: ModrinthVersion) -> Self {
+        let file = version
+            .files
+            .iter()
+            .find(|f| f.primary)
+            .or_else(|| version.files.first());
+
+        let dependencies = version
+            .dependencies
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|dep| {
+                let kind = match dep.dependency_type.as_str() {
+                    "required" => EdgeKind::Required,
+                    "incompatible" => EdgeKind::Incompatible,
+                    _ => return None,
+                };
+                Some(DependencyEdge {
+                    project_id: dep.project_id,
+                    version_id: dep.version_id,
+                    kind,
+                })
+            })
+            .collect();
+
+        Self {
+            filename: file.map(|f| f.filename.clone()),
+            url: file.map(|f| f.url.clone()),
+            project_id: version.project_id,
+            version_id: version.id,
+            dependencies,
+        }
+    }
+}
+
+impl DependencySource for ModrinthSource<'_> {
+    async fn get_nodes(&self, version_ids: &[String]) -> Result<Vec<DependencyNode>, ServiceError> {
+        let versions: Vec<ModrinthVersion> = self
+            .collection()
+            .find(doc! { "_id": { "$in": version_ids } })
+            .await?
+            .try_collect()
+            .await?;
+        Ok(versions.into_iter().map(DependencyNode::from).collect())
+    }
+
+    async fn find_latest(&self, project_id: &str) -> Result<Option<DependencyNode>, ServiceError> {
+        let mut filter = doc! { "project_id": project_id };
+        if let Some(loader) = &self.loader {
+            filter.insert("loaders", loader);
+        }
+        if let Some(game_version) = &self.game_version {
+            filter.insert("game_versions", game_version);
+        }
+
+        let version = self
+            .collection()
+            .find_one(filter)
+            .sort(doc! { "date_published": -1 })
+            .await?;
+        Ok(version.map(DependencyNode::from))
+    }
+}
+
+struct CurseforgeSource<'a> {
+    db: &'a Client,
+    loader_name: Option<&'static str>,
+    game_version: Option<String>,
+}
+
+impl CurseforgeSource<'_> {
+    fn collection(&self) -> mongodb::Collection<CurseforgeFile> {
+        self.db
+            .database(get_database_name().as_str())
+            .collection::<CurseforgeFile>("curseforge_files")
+    }
+}
+
+impl From<CurseforgeFile> for DependencyNode {
+    fn from(file: CurseforgeFile) -> Self {
+        let dependencies = file
+            .dependencies
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|dep| {
+                let kind = match dep.relation_type {
+                    Some(CURSEFORGE_REQUIRED_DEPENDENCY) => EdgeKind::Required,
+                    Some(CURSEFORGE_INCOMPATIBLE) => EdgeKind::Incompatible,
+                    _ => return None,
+                };
+                Some(DependencyEdge {
+                    project_id: Some(dep.mod_id.to_string()),
+                    version_id: None,
+                    kind,
+                })
+            })
+            .collect();
+
+        Self {
+            project_id: file.mod_id.to_string(),
+            version_id: file.id.to_string(),
+            filename: file.file_name,
+            url: file.download_url,
+            dependencies,
+        }
+    }
+}
+
+impl DependencySource for CurseforgeSource<'_> {
+    async fn get_nodes(&self, version_ids: &[String]) -> Result<Vec<DependencyNode>, ServiceError> {
+        let file_ids: Vec<i32> = version_ids
+            .iter()
+            .filter_map(|id| id.parse().ok())
+            .collect();
+        let files: Vec<CurseforgeFile> = self
+            .collection()
+            .find(doc! { "_id": { "$in": file_ids } })
+            .await?
+            .try_collect()
+            .await?;
+        Ok(files.into_iter().map(DependencyNode::from).collect())
+    }
+
+    async fn find_latest(&self, project_id: &str) -> Result<Option<DependencyNode>, ServiceError> {
+        let Ok(mod_id) = project_id.parse::<i32>() else {
+            return Ok(None);
+        };
+
+        // gameVersions 中同时包含游戏版本与 loader 名称
+        let mut filter = doc! { "modId": mod_id };
+        let required: Vec<String> = self
+            .game_version
+            .iter()
+            .cloned()
+            .chain(self.loader_name.map(String::from))
+            .collect();
+        if !required.is_empty() {
+            filter.insert("gameVersions", doc! { "$all": required });
+        }
+
+        let file = self
+            .collection()
+            .find_one(filter)
+            .sort(doc! { "fileDate": -1 })
+            .await?;
+        Ok(file.map(DependencyNode::from))
+    }
+}
+
+#[derive(Default)]
+struct Resolution {
+    response: DependencyResolveResponse,
+    // project_id -> install 中的下标
+    selected: HashMap<String, usize>,
+    incompatible_edges: Vec<(String, String, DependencyEdge)>,
+}
+
+impl Resolution {
+    // 项目已在安装列表中时记录依赖关系，版本不一致则记为冲突
+    fn require_selected(
+        &mut self,
+        project_id: &str,
+        version_id: Option<&str>,
+        required_by: Option<&str>,
+    ) -> bool {
+        let Some(&index) = self.selected.get(project_id) else {
+            return false;
+        };
+        let entry = &mut self.response.install[index];
+
+        if let Some(version_id) = version_id
+            && version_id != entry.version_id
+        {
+            self.response.conflicts.push(DependencyConflict {
+                project_id: project_id.to_string(),
+                selected_version_id: entry.version_id.clone(),
+                requested_version_id: version_id.to_string(),
+                requested_by: required_by.map(String::from),
+            });
+        }
+        if let Some(required_by) = required_by
+            && !entry.required_by.iter().any(|r| r == required_by)
+        {
+            entry.required_by.push(required_by.to_string());
+        }
+        true
+    }
+
+    // 返回 true 表示是新加入的项目，需要继续解析它的依赖
+    fn select(&mut self, node: &DependencyNode, depth: i32, required_by: Option<&str>) -> bool {
+        if self.require_selected(&node.project_id, Some(&node.version_id), required_by) {
+            return false;
+        }
+
+        self.selected
+            .insert(node.project_id.clone(), self.response.install.len());
+        self.response.install.push(ResolvedDependency {
+            project_id: node.project_id.clone(),
+            version_id: node.version_id.clone(),
+            filename: node.filename.clone(),
+            url: node.url.clone(),
+            depth,
+            required_by: required_by.map(String::from).into_iter().collect(),
+        });
+        true
+    }
+
+    fn check_incompatible(&mut self) {
+        for (project_id, version_id, edge) in &self.incompatible_edges {
+            let target = self.response.install.iter().find(|entry| {
+                edge.project_id.as_deref() == Some(entry.project_id.as_str())
+                    || edge.version_id.as_deref() == Some(entry.version_id.as_str())
+            });
+            if let Some(target) = target {
+                self.response.incompatible.push(IncompatibleDependency {
+                    project_id: project_id.clone(),
+                    version_id: version_id.clone(),
+                    incompatible_project_id: target.project_id.clone(),
+                    incompatible_version_id: target.version_id.clone(),
+                });
+            }
+        }
+    }
+}
+
+async fn resolve<S: DependencySource>(
+    source: &S,
+    root_ids: &[String],
+) -> Result<DependencyResolveResponse, ServiceError> {
+    if root_ids.is_empty() || root_ids.len() > MAX_ROOT_VERSIONS {
+        return Err(ServiceError::InvalidInput {
+            field: String::from("ids"),
+            reason: format!("Between 1 and {} ids are required", MAX_ROOT_VERSIONS),
+        });
+    }
+
+    let mut resolution = Resolution::default();
+    let mut queue = VecDeque::new();
+
+    let mut roots = source.get_nodes(root_ids).await?;
+    // 按请求顺序处理，同一项目的多个版本以先出现的为准
+    roots.sort_by_key(|root| root_ids.iter().position(|id| id == &root.version_id));
+    for root_id in root_ids {
+        if !roots.iter().any(|root| &root.version_id == root_id) {
+            resolution.response.unresolved.push(UnresolvedDependency {
+                project_id: None,
+                version_id: Some(root_id.clone()),
+                required_by: None,
+                reason: String::from("Version not found"),
+            });
+        }
+    }
+    for root in roots {
+        if resolution.select(&root, 0, None) {
+            queue.push_back((root, 0));
+        }
+    }
+
+    while let Some((node, depth)) = queue.pop_front() {
+        for edge in node.dependencies {
+            if let EdgeKind::Incompatible = edge.kind {
+                resolution.incompatible_edges.push((
+                    node.project_id.clone(),
+                    node.version_id.clone(),
+                    edge,
+                ));
+                continue;
+            }
+
+            if let Some(project_id) = &edge.project_id
+                && resolution.require_selected(
+                    project_id,
+                    edge.version_id.as_deref(),
+                    Some(&node.version_id),
+                )
+            {
+                continue;
+            }
+
+            // 指定了版本则使用该版本，否则选择最新的兼容版本
+            let dependency = match (&edge.version_id, &edge.project_id) {
+                (Some(version_id), _) => source
+                    .get_nodes(std::slice::from_ref(version_id))
+                    .await?
+                    .into_iter()
+                    .next(),
+                (None, Some(project_id)) => source.find_latest(project_id).await?,
+                (None, None) => None,
+            };
+
+            let Some(dependency) = dependency else {
+                resolution.response.unresolved.push(UnresolvedDependency {
+                    project_id: edge.project_id.clone(),
+                    version_id: edge.version_id.clone(),
+                    required_by: Some(node.version_id.clone()),
+                    reason: String::from("No compatible version found"),
+                });
+                continue;
+            };
+
+            if resolution.select(&dependency, depth + 1, Some(&node.version_id)) {
+                if resolution.response.install.len() > MAX_RESOLVED_DEPENDENCIES {
+                    return Err(ServiceError::InvalidInput {
+                        field: String::from("ids"),
+                        reason: format!(
+                            "Dependency tree exceeds {} entries",
+                            MAX_RESOLVED_DEPENDENCIES
+                        ),
+                    });
+                }
+                queue.push_back((dependency, depth + 1));
+            }
+        }
+    }
+
+    resolution.check_incompatible();
+    Ok(resolution.response)
+}
+
+pub struct DependencyService {
+    pub db: Client,
+}
+
+impl DependencyService {
+    pub fn new(db: Client) -> Self {
+        Self { db }
+    }
+
+    pub async fn resolve_modrinth(
+        &self,
+        version_ids: Vec<String>,
+        loader: Option<String>,
+        game_version: Option<String>,
+    ) -> Result<DependencyResolveResponse, ServiceError> {
+        let source = ModrinthSource {
+            db: &self.db,
+            loader: loader.map(|l| l.to_lowercase()),
+            game_version,
+        };
+        resolve(&source, &version_ids).await
+    }
+
+    pub async fn resolve_curseforge(
+        &self,
+        file_ids: Vec<i32>,
+        mod_loader_type: Option<i32>,
+        game_version: Option<String>,
+    ) -> Result<DependencyResolveResponse, ServiceError> {
+        let loader_name = match mod_loader_type {
+            None | Some(0) => None,
+            Some(loader_type) => Some(mod_loader_type_name(loader_type)?),
+        };
+        let source = CurseforgeSource {
+            db: &self.db,
+            loader_name,
+            game_version,
+        };
+        let file_ids: Vec<String> = file_ids.iter().map(i32::to_string).collect();
+        resolve(&source, &file_ids).await
+    }
+}
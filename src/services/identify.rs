@@ -0,0 +1,105 @@
+use actix_web::web;
+use bytes::Bytes;
+use mongodb::Client as Mongo_Client;
+use std::collections::HashMap;
+
+use crate::db::_redis::RedisPool;
+use crate::errors::ServiceError;
+use crate::models::curseforge::responses::Fingerprint as CurseforgeMatch;
+use crate::models::identify::responses::{IdentifiedFile, IdentifyResponse};
+use crate::models::modrinth::responses::Version as ModrinthVersion;
+use crate::services::curseforge::CurseforgeService;
+use crate::services::modrinth::ModrinthService;
+use crate::utils::fingerprint::FileHashes;
+
+pub struct UploadedFile {
+    pub filename: Option<String>,
+    pub data: Bytes,
+}
+
+pub struct IdentifyService {
+    db: Mongo_Client,
+    redis: RedisPool,
+}
+
+impl IdentifyService {
+    pub fn new(db: Mongo_Client, redis: RedisPool) -> Self {
+        Self { db, redis }
+    }
+
+    pub async fn identify(
+        &self,
+        files: Vec<UploadedFile>,
+    ) -> Result<IdentifyResponse, ServiceError> {
+        if files.is_empty() {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("file"),
+                reason: String::from("At least one file is required"),
+            });
+        }
+
+        // 哈希计算是 CPU 密集操作，放到阻塞线程池避免占用 worker
+        let hashed: Vec<(UploadedFile, FileHashes)> = web::block(move || {
+            files
+                .into_iter()
+                .map(|file| {
+                    let hashes = FileHashes::compute(&file.data);
+                    (file, hashes)
+                })
+                .collect()
+        })
+        .await
+        .map_err(|e| ServiceError::UnexpectedError(format!("Hashing failed: {}", e)))?;
+
+        let fingerprints: Vec<i64> = hashed
+            .iter()
+            .map(|(_, hashes)| hashes.fingerprint as i64)
+            .collect();
+        let sha1s: Vec<String> = hashed
+            .iter()
+            .map(|(_, hashes)| hashes.sha1.clone())
+            .collect();
+
+        // 两个平台的查询都会把未命中的指纹 / hash 提交到队列
+        let curseforge_service = CurseforgeService::new(self.db.clone(), self.redis.clone());
+        let modrinth_service = ModrinthService::new(self.db.clone(), self.redis.clone());
+        let (curseforge_result, modrinth_result) = futures::join!(
+            curseforge_service.get_fingerprints(fingerprints, None),
+            modrinth_service.get_version_files(sha1s, String::from("sha1"))
+        );
+
+        let curseforge_matches: HashMap<i64, CurseforgeMatch> = match curseforge_result {
+            Ok(result) => result
+                .data
+                .exact_matches
+                .into_iter()
+                .filter_map(|m| m.file.file_fingerprint.map(|fingerprint| (fingerprint, m)))
+                .collect(),
+            Err(ServiceError::NotFound { .. }) => HashMap::new(),
+            Err(e) => return Err(e),
+        };
+        let modrinth_matches: HashMap<String, ModrinthVersion> = match modrinth_result {
+            Ok(result) => result.entries.unwrap_or_default(),
+            Err(ServiceError::NotFound { .. }) => HashMap::new(),
+            Err(e) => return Err(e),
+        };
+
+        let files = hashed
+            .into_iter()
+            .map(|(file, hashes)| {
+                let fingerprint = hashes.fingerprint as i64;
+                IdentifiedFile {
+                    filename: file.filename,
+                    size: file.data.len() as i64,
+                    curseforge: curseforge_matches.get(&fingerprint).cloned(),
+                    modrinth: modrinth_matches.get(&hashes.sha1).cloned(),
+                    fingerprint,
+                    sha1: hashes.sha1,
+                    sha512: hashes.sha512,
+                }
+            })
+            .collect();
+
+        Ok(IdentifyResponse { files })
+    }
+}
@@ -0,0 +1,244 @@
+use bson::{Document, doc};
+use futures::stream::TryStreamExt;
+use mongodb::Client;
+use std::collections::HashMap;
+
+use crate::db::database::get_database_name;
+use crate::errors::ServiceError;
+use crate::models::curseforge::HASH_ALGO_SHA1;
+use crate::models::mapping::responses::{
+    CurseforgeMappingResponse, CurseforgeModMapping, ModrinthMappingResponse,
+    ModrinthProjectMapping,
+};
+
+pub struct MappingService {
+    pub db: Client,
+}
+
+impl MappingService {
+    pub fn new(db: Client) -> Self {
+        Self { db }
+    }
+
+    fn collection(&self, name: &str) -> mongodb::Collection<Document> {
+        self.db
+            .database(get_database_name().as_str())
+            .collection::<Document>(name)
+    }
+
+    pub async fn get_modrinth_mapping(
+        &self,
+        project_id_or_slug: &str,
+    ) -> Result<ModrinthMappingResponse, ServiceError> {
+        if project_id_or_slug.trim().is_empty() {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("project_id"),
+                reason: String::from("Project ID cannot be empty"),
+            });
+        }
+
+        let project = self
+            .collection("modrinth_projects")
+            .find_one(doc! { "$or": [
+                { "_id": project_id_or_slug },
+                { "slug": project_id_or_slug }
+            ] })
+            .projection(doc! { "_id": 1 })
+            .await?
+            .ok_or_else(|| ServiceError::NotFound {
+                resource: String::from("Modrinth Project"),
+                detail: Some(format!(
+                    "Project with ID or slug {} not found",
+                    project_id_or_slug
+                )),
+            })?;
+        let project_id = project
+            .get_str("_id")
+            .map_err(|e| ServiceError::UnexpectedError(format!("Invalid project _id: {}", e)))?
+            .to_string();
+
+        let files: Vec<Document> = self
+            .collection("modrinth_files")
+            .find(doc! { "project_id": &project_id })
+            .projection(doc! { "_id.sha1": 1 })
+            .await?
+            .try_collect()
+            .await?;
+        let sha1s: Vec<String> = files
+            .iter()
+            .filter_map(|file| file.get_document("_id").ok())
+            .filter_map(|hashes| hashes.get_str("sha1").ok())
+            .map(String::from)
+            .collect();
+
+        let matched = self
+            .count_matched_files("curseforge_files", "sha1", "modId", &sha1s)
+            .await?;
+        let mod_ids: Vec<i32> = matched.iter().filter_map(|(id, _)| id.as_i32()).collect();
+
+        let mods: HashMap<i32, Document> = self
+            .collection("curseforge_mods")
+            .find(doc! { "_id": { "$in": &mod_ids } })
+            .projection(doc! { "slug": 1, "name": 1 })
+            .await?
+            .try_collect::<Vec<Document>>()
+            .await?
+            .into_iter()
+            .filter_map(|m| m.get_i32("_id").ok().map(|id| (id, m)))
+            .collect();
+
+        let curseforge = matched
+            .into_iter()
+            .filter_map(|(id, matched_files)| {
+                let mod_id = id.as_i32()?;
+                let info = mods.get(&mod_id);
+                Some(CurseforgeModMapping {
+                    mod_id,
+                    slug: info.and_then(|m| m.get_str("slug").ok()).map(String::from),
+                    name: info.and_then(|m| m.get_str("name").ok()).map(String::from),
+                    matched_files,
+                })
+            })
+            .collect();
+
+        Ok(ModrinthMappingResponse {
+            project_id,
+            total_files: sha1s.len() as i64,
+            curseforge,
+        })
+    }
+
+    pub async fn get_curseforge_mapping(
+        &self,
+        mod_id: i32,
+    ) -> Result<CurseforgeMappingResponse, ServiceError> {
+        if mod_id <= 0 {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("mod_id"),
+                reason: String::from("Mod ID must be positive"),
+            });
+        }
+
+        if self
+            .collection("curseforge_mods")
+            .find_one(doc! { "_id": mod_id })
+            .projection(doc! { "_id": 1 })
+            .await?
+            .is_none()
+        {
+            return Err(ServiceError::NotFound {
+                resource: String::from("Curseforge Mod"),
+                detail: Some(format!("Mod with ID {} not found", mod_id)),
+            });
+        }
+
+        let files: Vec<Document> = self
+            .collection("curseforge_files")
+            .find(doc! { "modId": mod_id })
+            .projection(doc! { "sha1": 1, "hashes": 1 })
+            .await?
+            .try_collect()
+            .await?;
+        let sha1s: Vec<String> = files.iter().filter_map(curseforge_file_sha1).collect();
+
+        let matched = self
+            .count_matched_files("modrinth_files", "_id.sha1", "project_id", &sha1s)
+            .await?;
+        let project_ids: Vec<&str> = matched.iter().filter_map(|(id, _)| id.as_str()).collect();
+
+        let projects: HashMap<String, Document> = self
+            .collection("modrinth_projects")
+            .find(doc! { "_id": { "$in": &project_ids } })
+            .projection(doc! { "slug": 1, "title": 1 })
+            .await?
+            .try_collect::<Vec<Document>>()
+            .await?
+            .into_iter()
+            .filter_map(|p| p.get_str("_id").ok().map(String::from).map(|id| (id, p)))
+            .collect();
+
+        let modrinth = matched
+            .into_iter()
+            .filter_map(|(id, matched_files)| {
+                let project_id = id.as_str()?.to_string();
+                let info = projects.get(&project_id);
+                Some(ModrinthProjectMapping {
+                    slug: info.and_then(|p| p.get_str("slug").ok()).map(String::from),
+                    title: info.and_then(|p| p.get_str("title").ok()).map(String::from),
+                    project_id,
+                    matched_files,
+                })
+            })
+            .collect();
+
+        Ok(CurseforgeMappingResponse {
+            mod_id,
+            total_files: sha1s.len() as i64,
+            modrinth,
+        })
+    }
+
+    // 在另一个平台的文件集合中按 sha1 匹配，并按所属项目统计命中的文件数
+    async fn count_matched_files(
+        &self,
+        collection: &str,
+        sha1_field: &str,
+        group_field: &str,
+        sha1s: &[String],
+    ) -> Result<Vec<(bson::Bson, i64)>, ServiceError> {
+        if sha1s.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let pipeline = vec![
+            doc! { "$match": { sha1_field: { "$in": sha1s } } },
+            doc! { "$group": {
+                "_id": format!("${}", group_field),
+                "matched_files": { "$sum": 1 }
+            } },
+            doc! { "$sort": { "matched_files": -1, "_id": 1 } },
+        ];
+
+        let results: Vec<Document> = self
+            .collection(collection)
+            .aggregate(pipeline)
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: format!("Failed to match files in {}", collection),
+                source: Some(e),
+            })?
+            .try_collect()
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: format!("Failed to collect matched files in {}", collection),
+                source: Some(e),
+            })?;
+
+        Ok(results
+            .into_iter()
+            .filter_map(|mut doc| {
+                let id = doc.remove("_id")?;
+                let matched_files = match doc.get("matched_files")? {
+                    bson::Bson::Int32(n) => *n as i64,
+                    bson::Bson::Int64(n) => *n,
+                    _ => return None,
+                };
+                Some((id, matched_files))
+            })
+            .collect())
+    }
+}
+
+// 优先使用顶层 sha1 字段，旧数据从 hashes 中取
+fn curseforge_file_sha1(file: &Document) -> Option<String> {
+    if let Ok(sha1) = file.get_str("sha1") {
+        return Some(sha1.to_string());
+    }
+    file.get_array("hashes")
+        .ok()?
+        .iter()
+        .filter_map(|hash| hash.as_document())
+        .find(|hash| hash.get_i32("algo").ok() == Some(HASH_ALGO_SHA1))
+        .and_then(|hash| hash.get_str("value").ok())
+        .map(String::from)
+}
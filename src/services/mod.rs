@@ -1,6 +1,13 @@
+pub mod admin_token;
 pub mod common;
 pub mod curseforge;
+pub mod dependency;
+pub mod identify;
+pub mod mapping;
+pub mod modpack;
 pub mod modrinth;
+pub mod queue;
+pub mod search;
 pub mod translate;
 
 #[derive(Debug)]
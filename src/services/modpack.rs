@@ -0,0 +1,207 @@
+use mongodb::Client as Mongo_Client;
+use std::collections::HashMap;
+
+use crate::db::_redis::RedisPool;
+use crate::errors::ServiceError;
+use crate::models::curseforge::responses::File as CurseforgeFile;
+use crate::models::curseforge::{HASH_ALGO_MD5, HASH_ALGO_SHA1};
+use crate::models::modpack::requests::{CurseforgeManifest, ModpackManifest, ModrinthIndex};
+use crate::models::modpack::responses::{
+    ModpackFile, ModpackFileHashes, ModpackResolveResponse, UnresolvedModpackFile,
+};
+use crate::models::modrinth::responses::Version as ModrinthVersion;
+use crate::models::search::responses::Platform;
+use crate::services::curseforge::CurseforgeService;
+use crate::services::modrinth::ModrinthService;
+use crate::utils::app::AppState;
+use crate::utils::file_cdn_load_balance::{curseforge_file_url, rewrite_modrinth_file_url};
+
+const MAX_MODPACK_FILES: usize = 2000;
+
+pub struct ModpackService {
+    db: Mongo_Client,
+    redis: RedisPool,
+}
+
+impl ModpackService {
+    pub fn new(db: Mongo_Client, redis: RedisPool) -> Self {
+        Self { db, redis }
+    }
+
+    pub async fn resolve(
+        &self,
+        state: &AppState,
+        manifest: ModpackManifest,
+    ) -> Result<ModpackResolveResponse, ServiceError> {
+        match manifest {
+            ModpackManifest::Modrinth(index) => self.resolve_modrinth(state, index).await,
+            ModpackManifest::Curseforge(manifest) => self.resolve_curseforge(state, manifest).await,
+        }
+    }
+
+    async fn resolve_modrinth(
+        &self,
+        state: &AppState,
+        index: ModrinthIndex,
+    ) -> Result<ModpackResolveResponse, ServiceError> {
+        check_file_count(index.files.len())?;
+
+        let hashes: Vec<String> = index
+            .files
+            .iter()
+            .map(|file| file.hashes.sha1.to_lowercase())
+            .collect();
+
+        // get_version_files 会把未找到的 hash 提交到队列
+        let service = ModrinthService::new(self.db.clone(), self.redis.clone());
+        let versions: HashMap<String, ModrinthVersion> = match service
+            .get_version_files(hashes, String::from("sha1"))
+            .await
+        {
+            Ok(result) => result.entries.unwrap_or_default(),
+            Err(ServiceError::NotFound { .. }) => HashMap::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut files = Vec::new();
+        let mut unresolved = Vec::new();
+        for entry in index.files {
+            let sha1 = entry.hashes.sha1.to_lowercase();
+            let resolved = versions.get(&sha1).and_then(|version| {
+                version
+                    .files
+                    .iter()
+                    .find(|file| file.hashes.sha1 == sha1)
+                    .map(|file| (version, file))
+            });
+
+            // 按客户端 env 判断，optional 与 unsupported 视为非必需
+            let required = entry
+                .env
+                .as_ref()
+                .and_then(|env| env.get("client"))
+                .is_none_or(|client| client == "required");
+
+            match resolved {
+                Some((version, file)) => files.push(ModpackFile {
+                    path: Some(entry.path),
+                    project_id: version.project_id.clone(),
+                    version_id: version.id.clone(),
+                    filename: Some(file.filename.clone()),
+                    url: rewrite_modrinth_file_url(state, &file.url),
+                    hashes: ModpackFileHashes {
+                        sha1: Some(file.hashes.sha1.clone()),
+                        sha512: Some(file.hashes.sha512.clone()),
+                        md5: None,
+                    },
+                    size: Some(file.size),
+                    required,
+                }),
+                None => unresolved.push(UnresolvedModpackFile {
+                    path: Some(entry.path),
+                    project_id: None,
+                    version_id: None,
+                    sha1: Some(sha1),
+                    downloads: entry
+                        .downloads
+                        .unwrap_or_default()
+                        .iter()
+                        .map(|url| rewrite_modrinth_file_url(state, url))
+                        .collect(),
+                }),
+            }
+        }
+
+        Ok(ModpackResolveResponse {
+            platform: Platform::Modrinth,
+            name: index.name,
+            game_version: index
+                .dependencies
+                .and_then(|dependencies| dependencies.get("minecraft").cloned()),
+            files,
+            unresolved,
+        })
+    }
+
+    async fn resolve_curseforge(
+        &self,
+        state: &AppState,
+        manifest: CurseforgeManifest,
+    ) -> Result<ModpackResolveResponse, ServiceError> {
+        check_file_count(manifest.files.len())?;
+
+        let file_ids: Vec<i32> = manifest.files.iter().map(|file| file.file_id).collect();
+
+        // get_files 会把未找到的 fileId 提交到队列
+        let service = CurseforgeService::new(self.db.clone(), self.redis.clone());
+        let found: HashMap<i32, CurseforgeFile> = match service.get_files(file_ids).await {
+            Ok(result) => result
+                .data
+                .into_iter()
+                .map(|file| (file.id, file))
+                .collect(),
+            Err(ServiceError::NotFound { .. }) => HashMap::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut files = Vec::new();
+        let mut unresolved = Vec::new();
+        for entry in manifest.files {
+            let Some(file) = found.get(&entry.file_id) else {
+                unresolved.push(UnresolvedModpackFile {
+                    path: None,
+                    project_id: Some(entry.project_id.to_string()),
+                    version_id: Some(entry.file_id.to_string()),
+                    sha1: None,
+                    downloads: Vec::new(),
+                });
+                continue;
+            };
+
+            let find_hash = |algo: i32| {
+                file.hashes
+                    .iter()
+                    .flatten()
+                    .find(|hash| hash.algo == algo)
+                    .map(|hash| hash.value.clone())
+            };
+            let file_name = file.file_name.clone().unwrap_or_default();
+
+            files.push(ModpackFile {
+                path: None,
+                project_id: file.mod_id.to_string(),
+                version_id: file.id.to_string(),
+                url: curseforge_file_url(state, file.id / 1000, file.id % 1000, &file_name),
+                filename: file.file_name.clone(),
+                hashes: ModpackFileHashes {
+                    sha1: find_hash(HASH_ALGO_SHA1),
+                    sha512: None,
+                    md5: find_hash(HASH_ALGO_MD5),
+                },
+                size: file.file_length,
+                required: entry.required.unwrap_or(true),
+            });
+        }
+
+        Ok(ModpackResolveResponse {
+            platform: Platform::Curseforge,
+            name: manifest.name,
+            game_version: manifest.minecraft.map(|minecraft| minecraft.version),
+            files,
+            unresolved,
+        })
+    }
+}
+
+fn check_file_count(count: usize) -> Result<(), ServiceError> {
+    if count == 0 || count > MAX_MODPACK_FILES {
+        return Err(ServiceError::InvalidInput {
+            field: String::from("files"),
+            reason: format!(
+                "A modpack must contain between 1 and {} files",
+                MAX_MODPACK_FILES
+            ),
+        });
+    }
+    Ok(())
+}
@@ -1,24 +1,30 @@
-use bson::doc;
+use bson::{Bson, doc};
 use futures::stream::TryStreamExt;
 use mongodb::{Client as Mongo_Client, bson::Document};
 use redis::AsyncCommands;
-use redis::aio::MultiplexedConnection;
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use std::collections::HashMap;
-use std::sync::Arc;
 
+use crate::db::_redis::RedisPool;
 use crate::db::database::get_database_name;
+use crate::db::sync_queue::{
+    MODRINTH_HASHES_SHA1, MODRINTH_HASHES_SHA512, MODRINTH_PROJECT_IDS, MODRINTH_TEAM_IDS,
+    MODRINTH_USER_IDS, MODRINTH_VERSION_IDS, enqueue,
+};
 use crate::errors::ServiceError;
 use crate::models::modrinth::entities as db;
+use crate::models::modrinth::requests::SearchQuery;
 use crate::models::modrinth::responses::*;
+use crate::utils::app::SearchMode;
+use crate::utils::search::escape_regex;
 
 pub struct ModrinthService {
     db: Mongo_Client,
-    redis: Arc<MultiplexedConnection>,
+    redis: RedisPool,
 }
 
 impl ModrinthService {
-    pub fn new(db: Mongo_Client, redis: Arc<MultiplexedConnection>) -> Self {
+    pub fn new(db: Mongo_Client, redis: RedisPool) -> Self {
         Self { db, redis }
     }
 
@@ -28,7 +34,9 @@ impl ModrinthService {
         project_id: &str,
         slug: &str,
     ) -> Result<(), ServiceError> {
-        let mut conn = self.redis.as_ref().clone();
+        let Some(mut conn) = self.redis.get() else {
+            return Ok(());
+        };
         let ttl = 3600; // 1小时过期
 
         // 设置双向映射
@@ -54,7 +62,7 @@ impl ModrinthService {
 
     // 获取缓存的 project_id 通过 slug
     async fn get_cached_project_id(&self, slug: &str) -> Option<String> {
-        let mut conn = self.redis.as_ref().clone();
+        let mut conn = self.redis.get()?;
         let key = format!("modrinth:slug:{}", slug);
 
         match conn.get::<String, Option<String>>(key).await {
@@ -68,7 +76,7 @@ impl ModrinthService {
 
     // 获取缓存的 slug 通过 project_id
     async fn get_cached_slug(&self, project_id: &str) -> Option<String> {
-        let mut conn = self.redis.as_ref().clone();
+        let mut conn = self.redis.get()?;
         let key = format!("modrinth:project_id:{}", project_id);
 
         match conn.get::<String, Option<String>>(key).await {
@@ -91,7 +99,9 @@ impl ModrinthService {
         hash: &str,
         version_id: &str,
     ) -> Result<(), ServiceError> {
-        let mut conn = self.redis.as_ref().clone();
+        let Some(mut conn) = self.redis.get() else {
+            return Ok(());
+        };
         let ttl = 3600; // 1小时过期
 
         let hash_key = format!("modrinth:{}:{}", algorithm, hash);
@@ -113,7 +123,7 @@ impl ModrinthService {
 
     // 获取缓存的 version_id 通过 hash
     async fn get_cached_version_id(&self, algorithm: &str, hash: &str) -> Option<String> {
-        let mut conn = self.redis.as_ref().clone();
+        let mut conn = self.redis.get()?;
         let key = format!("modrinth:{}:{}", algorithm, hash);
 
         match conn.get::<String, Option<String>>(key).await {
@@ -130,72 +140,78 @@ impl ModrinthService {
         }
     }
 
-    async fn add_project_ids_into_queue(
-        &self,
-        project_ids: Vec<String>,
-    ) -> Result<(), ServiceError> {
-        if project_ids.is_empty() {
-            return Ok(());
+    // 将 project_id 或 slug 解析为 project_id
+    async fn resolve_project_id(&self, project_id_or_slug: &str) -> Result<String, ServiceError> {
+        // 优化: 首先尝试从缓存获取映射关系
+        if is_modrinth_id(project_id_or_slug) {
+            // 看起来像是 project_id，尝试获取对应的 slug
+            if let Some(slug) = self.get_cached_slug(project_id_or_slug).await {
+                log::trace!(
+                    "Found cached slug {} for project_id {}",
+                    slug,
+                    project_id_or_slug
+                );
+            }
+            return Ok(project_id_or_slug.to_string());
         }
 
-        let mut conn = self.redis.as_ref().clone();
-        conn.sadd::<&str, &Vec<String>, ()>("modrinth_project_ids", &project_ids)
-            .await
-            .map_err(|e| -> ServiceError {
-                ServiceError::ExternalServiceError {
-                    service: "Redis".into(),
-                    message: format!("Failed to add project ids to Redis queue: {}", e),
-                }
-            })?;
-        log::debug!("Added project ids to Redis queue: {:?}", project_ids);
-        Ok(())
-    }
+        // 看起来像是 slug，尝试获取对应的 project_id
+        if let Some(cached_id) = self.get_cached_project_id(project_id_or_slug).await {
+            log::trace!(
+                "Found cached project_id {} for slug {}",
+                cached_id,
+                project_id_or_slug
+            );
+            return Ok(cached_id);
+        }
 
-    async fn add_version_ids_into_queue(
-        &self,
-        version_ids: Vec<String>,
-    ) -> Result<(), ServiceError> {
-        if version_ids.is_empty() {
-            return Ok(());
+        // 缓存未命中，需要查询数据库
+        match self
+            .get_project_by_id_or_slug(project_id_or_slug.to_string())
+            .await?
+        {
+            Some(p) => Ok(p.id),
+            None => Err(ServiceError::NotFound {
+                resource: String::from("Modrinth Project"),
+                detail: Some(format!(
+                    "Project with ID or slug {} not found",
+                    project_id_or_slug
+                )),
+            }),
         }
+    }
 
-        let mut conn = self.redis.as_ref().clone();
-        conn.sadd::<&str, &Vec<String>, ()>("modrinth_version_ids", &version_ids)
-            .await
-            .map_err(|e| -> ServiceError {
-                ServiceError::ExternalServiceError {
-                    service: "Redis".into(),
-                    message: format!("Failed to add version ids to Redis queue: {}", e),
-                }
-            })?;
-        log::debug!("Added version ids to Redis queue: {:?}", version_ids);
-        Ok(())
+    async fn add_project_ids_into_queue(&self, project_ids: Vec<String>, source: &str) {
+        enqueue(&self.redis, MODRINTH_PROJECT_IDS, &project_ids, source).await;
     }
 
-    async fn add_hashes_into_queue(
-        &self,
-        algorithm: String,
-        hashes: Vec<String>,
-    ) -> Result<(), ServiceError> {
-        if hashes.is_empty() {
-            return Ok(()); // 如果 hash 为空，直接返回
-        }
+    async fn add_version_ids_into_queue(&self, version_ids: Vec<String>, source: &str) {
+        enqueue(&self.redis, MODRINTH_VERSION_IDS, &version_ids, source).await;
+    }
 
-        let mut conn = self.redis.as_ref().clone();
+    async fn add_team_ids_into_queue(&self, team_ids: Vec<String>, source: &str) {
+        enqueue(&self.redis, MODRINTH_TEAM_IDS, &team_ids, source).await;
+    }
 
-        conn.sadd::<&str, &Vec<String>, ()>(
-            format!("modrinth_hashes_{}", algorithm).as_str(),
-            &hashes,
-        )
-        .await
-        .map_err(|e| -> ServiceError {
-            ServiceError::ExternalServiceError {
-                service: "Redis".into(),
-                message: format!("Failed to add hash to Redis queue: {}", e),
+    async fn add_user_ids_into_queue(&self, user_ids: Vec<String>, source: &str) {
+        // 用户名无法被同步，只入队符合 ID 格式的值
+        let user_ids: Vec<String> = user_ids
+            .into_iter()
+            .filter(|user_id| is_modrinth_id(user_id))
+            .collect();
+        enqueue(&self.redis, MODRINTH_USER_IDS, &user_ids, source).await;
+    }
+
+    async fn add_hashes_into_queue(&self, algorithm: String, hashes: Vec<String>, source: &str) {
+        let queue = match algorithm.as_str() {
+            "sha1" => MODRINTH_HASHES_SHA1,
+            "sha512" => MODRINTH_HASHES_SHA512,
+            _ => {
+                log::warn!("Unsupported hash algorithm {} for sync queue", algorithm);
+                return;
             }
-        })?;
-        log::debug!("Added {}:{} to Redis queue", algorithm, hashes.join(","));
-        Ok(())
+        };
+        enqueue(&self.redis, queue, &hashes, source).await;
     }
 
     async fn check_search_result(&self, data: &serde_json::Value) -> Result<(), ServiceError> {
@@ -269,8 +285,8 @@ impl ModrinthService {
             .collect();
 
         if !not_found_project_ids.is_empty() {
-            self.add_project_ids_into_queue(not_found_project_ids)
-                .await?;
+            self.add_project_ids_into_queue(not_found_project_ids, "modrinth:search")
+                .await;
         } else {
             log::trace!("All projects have been found in the database.");
         }
@@ -288,17 +304,35 @@ impl ModrinthService {
         index: Option<String>,
         modrinth_api_url: &str,
     ) -> Result<serde_json::Value, ServiceError> {
+        let query = SearchQuery {
+            query,
+            facets,
+            offset,
+            limit,
+            index,
+        };
+        self.search_with_status(client, query, modrinth_api_url)
+            .await
+            .map(|(_, search_result)| search_result)
+    }
+
+    // 同时返回上游状态码，供 fallback 模式判断是否回退
+    async fn search_with_status(
+        &self,
+        client: &Client,
+        query: SearchQuery,
+        modrinth_api_url: &str,
+    ) -> Result<(StatusCode, serde_json::Value), ServiceError> {
+        let SearchQuery {
+            query,
+            facets,
+            offset,
+            limit,
+            index,
+        } = query;
         let api_url = format!("{}/v2/search", modrinth_api_url);
 
-        // 验证 index 在 SearchIndex 内
-        if let Some(ref idx) = index {
-            if !["relevance", "downloads", "follows", "newest", "updated"].contains(&idx.as_str()) {
-                return Err(ServiceError::InvalidInput {
-                    field: String::from("index"),
-                    reason: format!("Invalid index value: {}", idx),
-                });
-            }
-        }
+        validate_search_index(index.as_deref())?;
 
         let mut params = vec![];
         if let Some(q) = query {
@@ -335,6 +369,7 @@ impl ModrinthService {
                 service: String::from("Modrinth API"),
                 message: format!("Failed to read response body: {}", e),
             })?;
+
         let search_result = serde_json::from_slice(&bytes).map_err(|e| {
             ServiceError::UnexpectedError(format!(
                 "Failed to parse JSON: {}, text: {}",
@@ -351,7 +386,237 @@ impl ModrinthService {
             };
         }
 
-        Ok(search_result)
+        Ok((status, search_result))
+    }
+
+    pub async fn search_by_mode(
+        &self,
+        client: &Client,
+        query: &SearchQuery,
+        modrinth_api_url: &str,
+        mode: SearchMode,
+    ) -> Result<serde_json::Value, ServiceError> {
+        match mode {
+            SearchMode::Upstream => self.search_upstream(client, query, modrinth_api_url).await,
+            SearchMode::Local => self.search_local_json(query).await,
+            SearchMode::Fallback => {
+                match self
+                    .search_with_status(client, query.clone(), modrinth_api_url)
+                    .await
+                {
+                    // 上游 5xx 视为不可用，回退到本地搜索
+                    Ok((status, _)) if status.is_server_error() => {
+                        log::warn!(
+                            "Modrinth upstream search returned {}, fallback to local",
+                            status
+                        );
+                        self.search_local_json(query).await
+                    }
+                    Ok((_, result)) => Ok(result),
+                    // 参数错误不回退，直接返回给调用方
+                    Err(e @ ServiceError::InvalidInput { .. }) => Err(e),
+                    Err(e) => {
+                        log::warn!("Modrinth upstream search failed, fallback to local: {}", e);
+                        self.search_local_json(query).await
+                    }
+                }
+            }
+        }
+    }
+
+    async fn search_upstream(
+        &self,
+        client: &Client,
+        query: &SearchQuery,
+        modrinth_api_url: &str,
+    ) -> Result<serde_json::Value, ServiceError> {
+        self.search_with_status(client, query.clone(), modrinth_api_url)
+            .await
+            .map(|(_, search_result)| search_result)
+    }
+
+    async fn search_local_json(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<serde_json::Value, ServiceError> {
+        let result = self.search_local(query).await?;
+        serde_json::to_value(result).map_err(|e| {
+            ServiceError::UnexpectedError(format!("Failed to serialize search result: {}", e))
+        })
+    }
+
+    pub async fn search_local(&self, query: &SearchQuery) -> Result<SearchResponse, ServiceError> {
+        validate_search_index(query.index.as_deref())?;
+
+        let offset = query.offset.unwrap_or(0);
+        let limit = query.limit.unwrap_or(10);
+        if offset < 0 || !(1..=100).contains(&limit) {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("offset or limit"),
+                reason: String::from("offset must be non-negative and limit must be in 1..=100"),
+            });
+        }
+
+        // 与 Modrinth 一致，只搜索公开可见的项目
+        let mut conditions = vec![doc! { "status": { "$in": ["approved", "archived"] } }];
+
+        // 使用 title / slug / description 上的文本索引，见 db::indexes
+        let mut text_search = false;
+        if let Some(q) = &query.query {
+            let q = q.trim();
+            if !q.is_empty() {
+                conditions.push(doc! { "$text": { "$search": q } });
+                text_search = true;
+            }
+        }
+
+        if let Some(facets) = &query.facets {
+            conditions.extend(parse_facets(facets)?);
+        }
+
+        let sort = match query.index.as_deref() {
+            Some("follows") => doc! { "followers": -1, "_id": -1 },
+            Some("newest") => doc! { "published": -1, "_id": -1 },
+            Some("updated") => doc! { "updated": -1, "_id": -1 },
+            Some("downloads") => doc! { "downloads": -1, "_id": -1 },
+            // relevance 按文本匹配评分排序，没有 query 时按下载量排序
+            _ if text_search => doc! {
+                "score": { "$meta": "textScore" },
+                "downloads": -1,
+                "_id": -1
+            },
+            _ => doc! { "downloads": -1, "_id": -1 },
+        };
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<db::Project>("modrinth_projects");
+
+        let pipeline = vec![
+            doc! { "$match": { "$and": conditions } },
+            doc! {
+                "$facet": {
+                    "data": [
+                        doc! { "$sort": sort },
+                        doc! { "$skip": offset },
+                        doc! { "$limit": limit }
+                    ],
+                    "count": [
+                        doc! { "$count": "total" }
+                    ]
+                }
+            },
+        ];
+
+        let mut cursor =
+            collection
+                .aggregate(pipeline)
+                .await
+                .map_err(|e| ServiceError::DatabaseError {
+                    message: String::from("Failed to aggregate local search"),
+                    source: Some(e),
+                })?;
+
+        let result = cursor
+            .try_next()
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to fetch local search result"),
+                source: Some(e),
+            })?;
+
+        let (projects, total_hits) = match result {
+            Some(doc) => {
+                let data_array = doc.get_array("data").map_err(|_| {
+                    ServiceError::UnexpectedError("Failed to get data array".to_string())
+                })?;
+
+                let mut projects: Vec<db::Project> = Vec::new();
+                for item in data_array {
+                    if let Some(project_doc) = item.as_document() {
+                        let project: db::Project =
+                            bson::deserialize_from_document(project_doc.clone()).map_err(|e| {
+                                ServiceError::UnexpectedError(format!(
+                                    "Failed to deserialize Project: {}",
+                                    e
+                                ))
+                            })?;
+                        projects.push(project);
+                    }
+                }
+
+                let total_hits = doc
+                    .get_array("count")
+                    .ok()
+                    .and_then(|count| count.first())
+                    .and_then(|item| item.as_document())
+                    .and_then(|d| d.get_i32("total").ok())
+                    .unwrap_or(0);
+
+                (projects, total_hits)
+            }
+            None => (Vec::new(), 0),
+        };
+
+        let team_ids: Vec<String> = projects.iter().map(|p| p.team.clone()).collect();
+        let authors = self.get_team_owners(&team_ids).await?;
+
+        let hits = projects
+            .into_iter()
+            .map(|project| {
+                let author = authors.get(&project.team).cloned().unwrap_or_default();
+                search_hit_from_project(project, author)
+            })
+            .collect();
+
+        Ok(SearchResponse {
+            hits,
+            offset,
+            limit,
+            total_hits,
+        })
+    }
+
+    // 返回 team_id -> Owner 用户名，没有 Owner 时取第一个成员
+    async fn get_team_owners(
+        &self,
+        team_ids: &[String],
+    ) -> Result<HashMap<String, String>, ServiceError> {
+        if team_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<db::Team>("modrinth_teams");
+
+        let teams: Vec<db::Team> = collection
+            .find(doc! { "_id": { "$in": team_ids } })
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to query teams"),
+                source: Some(e),
+            })?
+            .try_collect()
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: String::from("Failed to collect teams"),
+                source: Some(e),
+            })?;
+
+        Ok(teams
+            .into_iter()
+            .filter_map(|team| {
+                let owner = team
+                    .members
+                    .iter()
+                    .find(|m| m.role == "Owner")
+                    .or_else(|| team.members.first())?;
+                Some((team.id.clone(), owner.user.username.clone()))
+            })
+            .collect())
     }
 
     pub async fn get_project_by_id_or_slug(
@@ -392,8 +657,11 @@ impl ModrinthService {
             //         project_id_or_slug
             //     )),
             None => {
-                self.add_project_ids_into_queue(vec![project_id_or_slug.clone()])
-                    .await?;
+                self.add_project_ids_into_queue(
+                    vec![project_id_or_slug.clone()],
+                    "modrinth:get_project",
+                )
+                .await;
                 Err(ServiceError::NotFound {
                     resource: String::from("Modrinth Project"),
                     detail: Some(format!(
@@ -419,7 +687,7 @@ impl ModrinthService {
 
         for item in project_ids_or_slugs.clone() {
             // 判断是否为project_id格式 (通常是8个字符的字母数字组合)
-            if item.len() == 8 && item.chars().all(|c| c.is_ascii_alphanumeric()) {
+            if is_modrinth_id(&item) {
                 resolved_project_ids.push(item);
             } else {
                 // 可能是slug，尝试从缓存获取对应的project_id
@@ -491,8 +759,8 @@ impl ModrinthService {
 
         // 将未找到的 Project 添加到队列
         if projects.is_empty() {
-            self.add_project_ids_into_queue(project_ids_or_slugs.clone())
-                .await?;
+            self.add_project_ids_into_queue(project_ids_or_slugs.clone(), "modrinth:get_projects")
+                .await;
             return Err(ServiceError::NotFound {
                 resource: String::from("Modrinth Project"),
                 detail: Some(format!(
@@ -516,7 +784,8 @@ impl ModrinthService {
                     "Unmatched project_ids or slugs found: {:?}, added to Redis queue for processing.",
                     not_found_items
                 );
-                self.add_project_ids_into_queue(not_found_items).await?;
+                self.add_project_ids_into_queue(not_found_items, "modrinth:get_projects")
+                    .await;
             } else {
                 log::trace!("All requested projects found in the database.");
             }
@@ -539,49 +808,7 @@ impl ModrinthService {
             });
         }
 
-        // 优化: 首先尝试从缓存获取映射关系
-        let project_id = if project_id_or_slug.len() == 8
-            && project_id_or_slug
-                .chars()
-                .all(|c| c.is_ascii_alphanumeric())
-        {
-            // 看起来像是 project_id，尝试获取对应的 slug
-            if let Some(slug) = self.get_cached_slug(&project_id_or_slug).await {
-                log::trace!(
-                    "Found cached slug {} for project_id {}",
-                    slug,
-                    project_id_or_slug
-                );
-            }
-            project_id_or_slug.clone()
-        } else {
-            // 看起来像是 slug，尝试获取对应的 project_id
-            if let Some(cached_id) = self.get_cached_project_id(&project_id_or_slug).await {
-                log::trace!(
-                    "Found cached project_id {} for slug {}",
-                    cached_id,
-                    project_id_or_slug
-                );
-                cached_id
-            } else {
-                // 缓存未命中，需要查询数据库
-                let project = self
-                    .get_project_by_id_or_slug(project_id_or_slug.clone())
-                    .await?;
-                match project {
-                    Some(p) => p.id,
-                    None => {
-                        return Err(ServiceError::NotFound {
-                            resource: String::from("Modrinth Project"),
-                            detail: Some(format!(
-                                "Project with ID or slug {} not found",
-                                project_id_or_slug
-                            )),
-                        });
-                    }
-                }
-            }
-        };
+        let project_id = self.resolve_project_id(&project_id_or_slug).await?;
 
         let version_collection = self
             .db
@@ -633,6 +860,191 @@ impl ModrinthService {
         Ok(versions)
     }
 
+    pub async fn get_project_version(
+        &self,
+        project_id_or_slug: String,
+        version_id_or_number: String,
+    ) -> Result<Version, ServiceError> {
+        if project_id_or_slug.is_empty() || version_id_or_number.is_empty() {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("project_id_or_slug or version_id_or_number"),
+                reason: String::from("project_id_or_slug and version_id_or_number cannot be empty"),
+            });
+        }
+
+        let project_id = self.resolve_project_id(&project_id_or_slug).await?;
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<db::Version>("modrinth_versions");
+
+        // 同一 version_number 可能对应多个 loader 的版本，取最新发布的
+        let filter = doc! {
+            "project_id": &project_id,
+            "$or": [
+                { "_id": &version_id_or_number },
+                { "version_number": &version_id_or_number }
+            ]
+        };
+
+        match collection
+            .find_one(filter)
+            .sort(doc! { "date_published": -1 })
+            .await?
+        {
+            Some(doc) => Ok(doc.into()),
+            None => {
+                // Project 存在但版本缺失，说明该 Project 的版本需要重新同步
+                self.add_project_ids_into_queue(
+                    vec![project_id.clone()],
+                    "modrinth:get_project_version",
+                )
+                .await;
+                Err(ServiceError::NotFound {
+                    resource: String::from("Modrinth Version"),
+                    detail: Some(format!(
+                        "Version with ID or number {} not found in project {}",
+                        version_id_or_number, project_id_or_slug
+                    )),
+                })
+            }
+        }
+    }
+
+    pub async fn get_project_dependencies(
+        &self,
+        project_id_or_slug: String,
+    ) -> Result<ProjectDependencies, ServiceError> {
+        if project_id_or_slug.is_empty() {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("project_id or slug"),
+                reason: String::from("project_id_or_slug cannot be empty"),
+            });
+        }
+
+        let project_id = self.resolve_project_id(&project_id_or_slug).await?;
+
+        let database = self.db.database(get_database_name().as_str());
+
+        // 汇总该 Project 所有版本的 dependencies
+        let mut cursor = database
+            .collection::<Document>("modrinth_versions")
+            .find(doc! { "project_id": &project_id })
+            .projection(doc! { "dependencies": 1 })
+            .await?;
+
+        let mut dependency_project_ids: Vec<String> = Vec::new();
+        let mut dependency_version_ids: Vec<String> = Vec::new();
+
+        while let Some(doc) = cursor
+            .try_next()
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: format!("Failed to fetch version documents: {}", e),
+                source: Some(e),
+            })?
+        {
+            let dependencies = match doc.get_array("dependencies") {
+                Ok(dependencies) => dependencies,
+                Err(_) => continue,
+            };
+
+            // 同时带有 version_id 与 project_id 时两者都收集，避免 Version 未入库时丢失 Project
+            for dependency in dependencies.iter().filter_map(|d| d.as_document()) {
+                if let Ok(version_id) = dependency.get_str("version_id")
+                    && !dependency_version_ids.iter().any(|id| id == version_id)
+                {
+                    dependency_version_ids.push(version_id.to_string());
+                }
+                if let Ok(dep_project_id) = dependency.get_str("project_id")
+                    && !dependency_project_ids.iter().any(|id| id == dep_project_id)
+                {
+                    dependency_project_ids.push(dep_project_id.to_string());
+                }
+            }
+        }
+
+        // 查询被依赖的 Version
+        let mut versions: Vec<Version> = Vec::new();
+        if !dependency_version_ids.is_empty() {
+            let mut cursor = database
+                .collection::<db::Version>("modrinth_versions")
+                .find(doc! { "_id": { "$in": &dependency_version_ids } })
+                .await?;
+
+            while let Some(doc) =
+                cursor
+                    .try_next()
+                    .await
+                    .map_err(|e| ServiceError::DatabaseError {
+                        message: format!("Failed to fetch version documents: {}", e),
+                        source: Some(e),
+                    })?
+            {
+                versions.push(doc.into());
+            }
+
+            let not_found_version_ids: Vec<String> = dependency_version_ids
+                .iter()
+                .filter(|v_id| !versions.iter().any(|v| v.id == **v_id))
+                .cloned()
+                .collect();
+
+            if !not_found_version_ids.is_empty() {
+                self.add_version_ids_into_queue(
+                    not_found_version_ids,
+                    "modrinth:get_project_dependencies",
+                )
+                .await;
+            }
+        }
+
+        // 被依赖 Version 所属的 Project 同样需要返回
+        for version in &versions {
+            if !dependency_project_ids.contains(&version.project_id) {
+                dependency_project_ids.push(version.project_id.clone());
+            }
+        }
+
+        // 查询被依赖的 Project
+        let mut projects: Vec<Project> = Vec::new();
+        if !dependency_project_ids.is_empty() {
+            let mut cursor = database
+                .collection::<db::Project>("modrinth_projects")
+                .find(doc! { "_id": { "$in": &dependency_project_ids } })
+                .await?;
+
+            while let Some(doc) =
+                cursor
+                    .try_next()
+                    .await
+                    .map_err(|e| ServiceError::DatabaseError {
+                        message: format!("Failed to fetch project documents: {}", e),
+                        source: Some(e),
+                    })?
+            {
+                projects.push(doc.into());
+            }
+
+            let not_found_project_ids: Vec<String> = dependency_project_ids
+                .iter()
+                .filter(|p_id| !projects.iter().any(|p| p.id == **p_id))
+                .cloned()
+                .collect();
+
+            if !not_found_project_ids.is_empty() {
+                self.add_project_ids_into_queue(
+                    not_found_project_ids,
+                    "modrinth:get_project_dependencies",
+                )
+                .await;
+            }
+        }
+
+        Ok(ProjectDependencies { projects, versions })
+    }
+
     pub async fn get_version(&self, version_id: String) -> Result<Option<Version>, ServiceError> {
         if version_id.is_empty() {
             return Err(ServiceError::InvalidInput {
@@ -649,8 +1061,8 @@ impl ModrinthService {
         match collection.find_one(doc! { "_id": &version_id }).await? {
             Some(doc) => Ok(Some(doc.into())),
             None => {
-                self.add_version_ids_into_queue(vec![version_id.clone()])
-                    .await?;
+                self.add_version_ids_into_queue(vec![version_id.clone()], "modrinth:get_version")
+                    .await;
                 Err(ServiceError::NotFound {
                     resource: String::from("Modrinth Version"),
                     detail: Some(format!("Version with ID {} not found", version_id)),
@@ -694,7 +1106,8 @@ impl ModrinthService {
         }
 
         if versions.is_empty() {
-            self.add_version_ids_into_queue(version_ids.clone()).await?;
+            self.add_version_ids_into_queue(version_ids.clone(), "modrinth:get_versions")
+                .await;
             return Err(ServiceError::NotFound {
                 resource: String::from("Modrinth Version"),
                 detail: Some(format!(
@@ -710,8 +1123,8 @@ impl ModrinthService {
                 .collect();
 
             if !not_found_version_ids.is_empty() {
-                self.add_version_ids_into_queue(not_found_version_ids)
-                    .await?;
+                self.add_version_ids_into_queue(not_found_version_ids, "modrinth:get_versions")
+                    .await;
             } else {
                 log::trace!("All requested versions found in the database.");
             }
@@ -774,8 +1187,12 @@ impl ModrinthService {
                 }
             }
             None => {
-                self.add_hashes_into_queue(algorithm.clone(), vec![hash.clone()])
-                    .await?;
+                self.add_hashes_into_queue(
+                    algorithm.clone(),
+                    vec![hash.clone()],
+                    "modrinth:get_version_file",
+                )
+                .await;
                 Err(ServiceError::NotFound {
                     resource: String::from("Modrinth files"),
                     detail: Some(format!("File with {} {} not found", algorithm, hash)),
@@ -822,8 +1239,12 @@ impl ModrinthService {
         }
 
         if files.is_empty() {
-            self.add_hashes_into_queue(algorithm.clone(), hashes.clone())
-                .await?;
+            self.add_hashes_into_queue(
+                algorithm.clone(),
+                hashes.clone(),
+                "modrinth:get_version_files",
+            )
+            .await;
             return Err(ServiceError::NotFound {
                 resource: String::from("Modrinth files"),
                 detail: Some(format!(
@@ -886,8 +1307,12 @@ impl ModrinthService {
         }
 
         if result.is_empty() {
-            self.add_hashes_into_queue(algorithm.clone(), hashes.clone())
-                .await?;
+            self.add_hashes_into_queue(
+                algorithm.clone(),
+                hashes.clone(),
+                "modrinth:get_version_files",
+            )
+            .await;
             return Err(ServiceError::NotFound {
                 resource: String::from("Modrinth version files"),
                 detail: Some(format!(
@@ -905,8 +1330,12 @@ impl ModrinthService {
             .collect();
 
         if !not_found_hashes.is_empty() {
-            self.add_hashes_into_queue(algorithm.clone(), not_found_hashes)
-                .await?;
+            self.add_hashes_into_queue(
+                algorithm.clone(),
+                not_found_hashes,
+                "modrinth:get_version_files",
+            )
+            .await;
         } else {
             log::trace!("All requested hashes found in the database.");
         }
@@ -993,8 +1422,12 @@ impl ModrinthService {
                 ))),
             }
         } else {
-            self.add_hashes_into_queue(algorithm.clone(), vec![hash.clone()])
-                .await?;
+            self.add_hashes_into_queue(
+                algorithm.clone(),
+                vec![hash.clone()],
+                "modrinth:get_version_file_update",
+            )
+            .await;
             Err(ServiceError::NotFound {
                 resource: String::from("Modrinth version file"),
                 detail: Some(format!("No matching version file found for hash {}", hash)),
@@ -1103,8 +1536,12 @@ impl ModrinthService {
             //         hashes
             //     )),
             // });
-            self.add_hashes_into_queue(algorithm.clone(), hashes.clone())
-                .await?;
+            self.add_hashes_into_queue(
+                algorithm.clone(),
+                hashes.clone(),
+                "modrinth:get_version_files_update",
+            )
+            .await;
             return Ok(MutilFilesResponse { entries: None }); // 官方返回的是 {}
         } else {
             let not_found_hashes = hashes
@@ -1114,8 +1551,12 @@ impl ModrinthService {
                 .collect::<Vec<String>>();
 
             if !not_found_hashes.is_empty() {
-                self.add_hashes_into_queue(algorithm.clone(), not_found_hashes)
-                    .await?;
+                self.add_hashes_into_queue(
+                    algorithm.clone(),
+                    not_found_hashes,
+                    "modrinth:get_version_files_update",
+                )
+                .await;
             } else {
                 log::trace!("All requested hashes found in the database.");
             }
@@ -1126,6 +1567,189 @@ impl ModrinthService {
         })
     }
 
+    pub async fn get_project_members(
+        &self,
+        project_id_or_slug: String,
+    ) -> Result<Vec<TeamMember>, ServiceError> {
+        let project = self
+            .get_project_by_id_or_slug(project_id_or_slug.clone())
+            .await?
+            .ok_or_else(|| ServiceError::NotFound {
+                resource: String::from("Modrinth Project"),
+                detail: Some(format!(
+                    "Project with ID or slug {} not found",
+                    project_id_or_slug
+                )),
+            })?;
+
+        self.get_team_members(project.team).await
+    }
+
+    pub async fn get_team_members(&self, team_id: String) -> Result<Vec<TeamMember>, ServiceError> {
+        if team_id.is_empty() {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("team_id"),
+                reason: String::from("team_id cannot be empty"),
+            });
+        }
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<db::Team>("modrinth_teams");
+
+        match collection.find_one(doc! { "_id": &team_id }).await? {
+            Some(team) => Ok(TeamMember::from_team(team)),
+            None => {
+                self.add_team_ids_into_queue(vec![team_id.clone()], "modrinth:get_team_members")
+                    .await;
+                Err(ServiceError::NotFound {
+                    resource: String::from("Modrinth Team"),
+                    detail: Some(format!("Team with ID {} not found", team_id)),
+                })
+            }
+        }
+    }
+
+    pub async fn get_teams(
+        &self,
+        team_ids: Vec<String>,
+    ) -> Result<Vec<Vec<TeamMember>>, ServiceError> {
+        if team_ids.is_empty() {
+            return Ok(Vec::new()); // 官方返回的是 []
+        }
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<db::Team>("modrinth_teams");
+
+        let mut cursor = collection
+            .find(doc! { "_id": { "$in": &team_ids } })
+            .await?;
+
+        let mut teams: Vec<db::Team> = Vec::new();
+
+        while let Some(doc) = cursor
+            .try_next()
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: format!("Failed to fetch team documents: {}", e),
+                source: Some(e),
+            })?
+        {
+            teams.push(doc);
+        }
+
+        let not_found_team_ids: Vec<String> = team_ids
+            .iter()
+            .filter(|t_id| !teams.iter().any(|t| t.id == **t_id))
+            .cloned()
+            .collect();
+
+        if !not_found_team_ids.is_empty() {
+            self.add_team_ids_into_queue(not_found_team_ids, "modrinth:get_teams")
+                .await;
+        } else {
+            log::trace!("All requested teams found in the database.");
+        }
+
+        Ok(teams.into_iter().map(TeamMember::from_team).collect())
+    }
+
+    pub async fn get_user(&self, user_id_or_username: String) -> Result<User, ServiceError> {
+        if user_id_or_username.is_empty() {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("user_id or username"),
+                reason: String::from("user_id_or_username cannot be empty"),
+            });
+        }
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<db::User>("modrinth_users");
+
+        match collection
+            .find_one(doc! { "$or": [
+                { "_id": &user_id_or_username },
+                { "username": &user_id_or_username }
+            ] })
+            .await?
+        {
+            Some(doc) => Ok(doc.into()),
+            None => {
+                self.add_user_ids_into_queue(
+                    vec![user_id_or_username.clone()],
+                    "modrinth:get_user",
+                )
+                .await;
+                Err(ServiceError::NotFound {
+                    resource: String::from("Modrinth User"),
+                    detail: Some(format!(
+                        "User with ID or username {} not found",
+                        user_id_or_username
+                    )),
+                })
+            }
+        }
+    }
+
+    pub async fn get_user_projects(
+        &self,
+        user_id_or_username: String,
+    ) -> Result<Vec<Project>, ServiceError> {
+        let user = self.get_user(user_id_or_username).await?;
+
+        let database = self.db.database(get_database_name().as_str());
+
+        // 先找出用户所在的 Team，再查询这些 Team 的 Project
+        let mut cursor = database
+            .collection::<Document>("modrinth_teams")
+            .find(doc! { "members.user.id": &user.id })
+            .projection(doc! { "_id": 1 })
+            .await?;
+
+        let mut team_ids: Vec<String> = Vec::new();
+
+        while let Some(doc) = cursor
+            .try_next()
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: format!("Failed to fetch team documents: {}", e),
+                source: Some(e),
+            })?
+        {
+            if let Ok(id) = doc.get_str("_id") {
+                team_ids.push(id.to_string());
+            }
+        }
+
+        if team_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut cursor = database
+            .collection::<db::Project>("modrinth_projects")
+            .find(doc! { "team": { "$in": &team_ids } })
+            .await?;
+
+        let mut projects: Vec<Project> = Vec::new();
+
+        while let Some(doc) = cursor
+            .try_next()
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: format!("Failed to fetch project documents: {}", e),
+                source: Some(e),
+            })?
+        {
+            projects.push(doc.into());
+        }
+
+        Ok(projects)
+    }
+
     pub async fn get_categories(&self) -> Result<Vec<Category>, ServiceError> {
         let collection = self
             .db
@@ -1210,6 +1834,124 @@ impl ModrinthService {
 
         Ok(game_versions)
     }
+
+    pub async fn get_licenses(&self) -> Result<Vec<LicenseTag>, ServiceError> {
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<db::LicenseTag>("modrinth_licenses");
+
+        let cursor = collection
+            .find(doc! {})
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: e.to_string(),
+                source: Some(e),
+            })?;
+
+        let db_licenses: Vec<db::LicenseTag> =
+            cursor
+                .try_collect()
+                .await
+                .map_err(|e| ServiceError::DatabaseError {
+                    message: e.to_string(),
+                    source: Some(e),
+                })?;
+
+        let licenses: Vec<LicenseTag> = db_licenses.into_iter().map(Into::into).collect();
+
+        Ok(licenses)
+    }
+
+    pub async fn get_license_text(&self, license_id: String) -> Result<LicenseText, ServiceError> {
+        if license_id.is_empty() {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("license_id"),
+                reason: String::from("license_id cannot be empty"),
+            });
+        }
+
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<db::LicenseText>("modrinth_license_texts");
+
+        match collection.find_one(doc! { "_id": &license_id }).await? {
+            Some(doc) => Ok(doc.into()),
+            None => Err(ServiceError::NotFound {
+                resource: String::from("Modrinth License"),
+                detail: Some(format!("License with ID {} not found", license_id)),
+            }),
+        }
+    }
+
+    pub async fn get_donation_platforms(&self) -> Result<Vec<DonationPlatform>, ServiceError> {
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<db::DonationPlatform>("modrinth_donation_platforms");
+
+        let cursor = collection
+            .find(doc! {})
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: e.to_string(),
+                source: Some(e),
+            })?;
+
+        let db_platforms: Vec<db::DonationPlatform> =
+            cursor
+                .try_collect()
+                .await
+                .map_err(|e| ServiceError::DatabaseError {
+                    message: e.to_string(),
+                    source: Some(e),
+                })?;
+
+        let platforms: Vec<DonationPlatform> = db_platforms.into_iter().map(Into::into).collect();
+
+        Ok(platforms)
+    }
+
+    async fn get_tag_names(&self, collection_name: &str) -> Result<Vec<TagName>, ServiceError> {
+        let collection = self
+            .db
+            .database(get_database_name().as_str())
+            .collection::<db::TagName>(collection_name);
+
+        let cursor = collection
+            .find(doc! {})
+            .await
+            .map_err(|e| ServiceError::DatabaseError {
+                message: e.to_string(),
+                source: Some(e),
+            })?;
+
+        let db_tags: Vec<db::TagName> =
+            cursor
+                .try_collect()
+                .await
+                .map_err(|e| ServiceError::DatabaseError {
+                    message: e.to_string(),
+                    source: Some(e),
+                })?;
+
+        let tags: Vec<TagName> = db_tags.into_iter().map(Into::into).collect();
+
+        Ok(tags)
+    }
+
+    pub async fn get_project_types(&self) -> Result<Vec<TagName>, ServiceError> {
+        self.get_tag_names("modrinth_project_types").await
+    }
+
+    pub async fn get_side_types(&self) -> Result<Vec<TagName>, ServiceError> {
+        self.get_tag_names("modrinth_side_types").await
+    }
+
+    pub async fn get_report_types(&self) -> Result<Vec<TagName>, ServiceError> {
+        self.get_tag_names("modrinth_report_types").await
+    }
 }
 
 pub fn default_algorithm_from_hashes(hashes: &[String]) -> String {
@@ -1225,3 +1967,147 @@ pub fn default_algorithm_from_hashes(hashes: &[String]) -> String {
     }
     "sha1".into()
 }
+
+// Modrinth 的 ID 为 8 位 base62 字符串
+pub fn is_modrinth_id(value: &str) -> bool {
+    value.len() == 8 && value.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn validate_search_index(index: Option<&str>) -> Result<(), ServiceError> {
+    match index {
+        None | Some("relevance" | "downloads" | "follows" | "newest" | "updated") => Ok(()),
+        Some(idx) => Err(ServiceError::InvalidInput {
+            field: String::from("index"),
+            reason: format!("Invalid index value: {}", idx),
+        }),
+    }
+}
+
+// facets 格式为 [["a:x","a:y"],["b:z"]]，内层为 OR，外层为 AND
+fn parse_facets(facets: &str) -> Result<Vec<Document>, ServiceError> {
+    let groups: Vec<Vec<String>> =
+        serde_json::from_str(facets).map_err(|e| ServiceError::InvalidInput {
+            field: String::from("facets"),
+            reason: format!("Invalid facets: {}", e),
+        })?;
+
+    let mut conditions = Vec::new();
+    for group in groups {
+        let mut alternatives = Vec::new();
+        for facet in &group {
+            alternatives.push(parse_facet(facet)?);
+        }
+        if !alternatives.is_empty() {
+            conditions.push(doc! { "$or": alternatives });
+        }
+    }
+    Ok(conditions)
+}
+
+fn parse_facet(facet: &str) -> Result<Document, ServiceError> {
+    let invalid = |reason: String| ServiceError::InvalidInput {
+        field: String::from("facets"),
+        reason,
+    };
+
+    let op_start = facet
+        .find([':', '=', '!', '<', '>'])
+        .ok_or_else(|| invalid(format!("Missing operator in facet {}", facet)))?;
+    let (key, rest) = facet.split_at(op_start);
+    let (op, value) = ["!=", ">=", "<=", ":", "=", ">", "<"]
+        .iter()
+        .find_map(|op| rest.strip_prefix(op).map(|value| (*op, value)))
+        .ok_or_else(|| invalid(format!("Invalid operator in facet {}", facet)))?;
+    let key = key.trim();
+    let value = value.trim();
+
+    let fields: &[&str] = match key {
+        "categories" => &["categories", "additional_categories", "loaders"],
+        "versions" => &["game_versions"],
+        "project_type" | "project_types" => &["project_type"],
+        "client_side" => &["client_side"],
+        "server_side" => &["server_side"],
+        "license" => &["license.id"],
+        "title" => &["title"],
+        "project_id" => &["_id"],
+        "downloads" => &["downloads"],
+        "follows" => &["followers"],
+        _ => return Err(invalid(format!("Unsupported facet {}", key))),
+    };
+
+    let value: Bson = match key {
+        "downloads" | "follows" => value
+            .parse::<i64>()
+            .map_err(|_| invalid(format!("Facet {} expects a number, got {}", key, value)))?
+            .into(),
+        // license 不区分大小写
+        "license" => doc! {
+            "$regex": format!("^{}$", escape_regex(value)),
+            "$options": "i"
+        }
+        .into(),
+        _ => value.into(),
+    };
+
+    if !matches!(op, ":" | "=" | "!=") && !matches!(value, Bson::Int64(_)) {
+        return Err(invalid(format!(
+            "Operator {} is not supported for facet {}",
+            op, key
+        )));
+    }
+
+    let condition = |field: &str| match op {
+        ">" => doc! { field: { "$gt": value.clone() } },
+        ">=" => doc! { field: { "$gte": value.clone() } },
+        "<" => doc! { field: { "$lt": value.clone() } },
+        "<=" => doc! { field: { "$lte": value.clone() } },
+        _ => doc! { field: value.clone() },
+    };
+    let conditions: Vec<Document> = fields.iter().map(|field| condition(field)).collect();
+
+    // 多字段时任一命中即视为命中，不等于则要求全部不命中
+    Ok(match op {
+        "!=" => doc! { "$nor": conditions },
+        _ if conditions.len() == 1 => conditions[0].clone(),
+        _ => doc! { "$or": conditions },
+    })
+}
+
+fn search_hit_from_project(project: db::Project, author: String) -> SearchHit {
+    let categories = project.categories.unwrap_or_default();
+    let loaders = project.loaders.unwrap_or_default();
+    let display_categories: Vec<String> =
+        categories.iter().chain(loaders.iter()).cloned().collect();
+    let all_categories: Vec<String> = categories
+        .iter()
+        .chain(project.additional_categories.iter().flatten())
+        .chain(loaders.iter())
+        .cloned()
+        .collect();
+    let game_versions = project.game_versions.unwrap_or_default();
+    let gallery = project.gallery.unwrap_or_default();
+
+    SearchHit {
+        project_id: project.id,
+        project_type: project.project_type.unwrap_or_default(),
+        slug: project.slug,
+        author,
+        title: project.title.unwrap_or_default(),
+        description: project.description.unwrap_or_default(),
+        categories: all_categories,
+        display_categories: Some(display_categories),
+        latest_version: game_versions.last().cloned(),
+        versions: game_versions,
+        downloads: project.downloads.unwrap_or(0),
+        follows: project.followers,
+        icon_url: project.icon_url.unwrap_or_default(),
+        date_created: project.published,
+        date_modified: project.updated,
+        license: project.license.and_then(|l| l.id).unwrap_or_default(),
+        client_side: project.client_side.unwrap_or_default(),
+        server_side: project.server_side.unwrap_or_default(),
+        featured_gallery: gallery.iter().find(|g| g.featured).map(|g| g.url.clone()),
+        gallery: Some(gallery.into_iter().map(|g| g.url).collect()),
+        color: project.color.unwrap_or(0),
+    }
+}
@@ -0,0 +1,213 @@
+use redis::RedisError;
+use redis::aio::MultiplexedConnection;
+
+use crate::db::_redis::RedisPool;
+use crate::db::sync_queue::{
+    CURSEFORGE_FILEIDS, CURSEFORGE_FINGERPRINTS, CURSEFORGE_MODIDS, MODRINTH_HASHES_SHA1,
+    MODRINTH_HASHES_SHA512, QUEUES, clear_queue, push_members, queue_entries, queue_stats,
+    remove_members,
+};
+use crate::errors::ServiceError;
+use crate::models::admin::responses::{
+    QueueDetail, QueueEntry, QueueMutationResponse, QueueSummary,
+};
+use crate::services::modrinth::is_modrinth_id;
+
+// 统计入队速率的时间窗口
+const RATE_WINDOW: i64 = 3600;
+const MAX_ENTRIES_LIMIT: isize = 1000;
+const MAX_MEMBERS: usize = 1000;
+
+pub struct QueueService {
+    redis: RedisPool,
+}
+
+impl QueueService {
+    pub fn new(redis: RedisPool) -> Self {
+        Self { redis }
+    }
+
+    fn connection(&self) -> Result<MultiplexedConnection, ServiceError> {
+        self.redis
+            .get()
+            .ok_or_else(|| ServiceError::ExternalServiceError {
+                service: String::from("Redis"),
+                message: String::from("Redis is unavailable"),
+            })
+    }
+
+    pub async fn list_queues(&self) -> Result<Vec<QueueSummary>, ServiceError> {
+        let mut conn = self.connection()?;
+        let mut summaries = Vec::with_capacity(QUEUES.len());
+        for queue in QUEUES {
+            summaries.push(summary(&mut conn, queue).await?);
+        }
+        Ok(summaries)
+    }
+
+    pub async fn get_queue(
+        &self,
+        queue: &str,
+        offset: Option<isize>,
+        limit: Option<isize>,
+    ) -> Result<QueueDetail, ServiceError> {
+        let queue = check_queue(queue)?;
+        let offset = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(100);
+        if offset < 0 || !(1..=MAX_ENTRIES_LIMIT).contains(&limit) {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("offset or limit"),
+                reason: format!(
+                    "offset must be non-negative and limit must be between 1 and {}",
+                    MAX_ENTRIES_LIMIT
+                ),
+            });
+        }
+
+        let mut conn = self.connection()?;
+        let summary = summary(&mut conn, queue).await?;
+        let entries = queue_entries(&mut conn, queue, offset, limit)
+            .await
+            .map_err(redis_error)?
+            .into_iter()
+            .map(
+                |(member, hit_count, first_seen, last_seen, source)| QueueEntry {
+                    member,
+                    hit_count,
+                    first_seen,
+                    last_seen,
+                    source,
+                },
+            )
+            .collect();
+
+        Ok(QueueDetail {
+            summary,
+            offset,
+            limit,
+            entries,
+        })
+    }
+
+    pub async fn remove_members(
+        &self,
+        queue: &str,
+        members: Vec<String>,
+    ) -> Result<QueueMutationResponse, ServiceError> {
+        let queue = check_queue(queue)?;
+        check_members(&members)?;
+
+        let mut conn = self.connection()?;
+        let affected = remove_members(&mut conn, queue, &members)
+            .await
+            .map_err(redis_error)?;
+        log::info!("Removed {} members from sync queue {}", affected, queue);
+
+        Ok(QueueMutationResponse {
+            queue: queue.to_string(),
+            affected,
+        })
+    }
+
+    pub async fn requeue_members(
+        &self,
+        queue: &str,
+        members: Vec<String>,
+    ) -> Result<QueueMutationResponse, ServiceError> {
+        let queue = check_queue(queue)?;
+        check_members(&members)?;
+        check_member_format(queue, &members)?;
+
+        let mut conn = self.connection()?;
+        push_members(&mut conn, queue, &members, "admin:requeue")
+            .await
+            .map_err(redis_error)?;
+        log::info!(
+            "Requeued {} members into sync queue {}",
+            members.len(),
+            queue
+        );
+
+        Ok(QueueMutationResponse {
+            queue: queue.to_string(),
+            affected: members.len() as u64,
+        })
+    }
+
+    pub async fn clear_queue(&self, queue: &str) -> Result<QueueMutationResponse, ServiceError> {
+        let queue = check_queue(queue)?;
+
+        let mut conn = self.connection()?;
+        let affected = clear_queue(&mut conn, queue).await.map_err(redis_error)?;
+        log::warn!("Cleared sync queue {} with {} members", queue, affected);
+
+        Ok(QueueMutationResponse {
+            queue: queue.to_string(),
+            affected,
+        })
+    }
+}
+
+async fn summary(
+    conn: &mut MultiplexedConnection,
+    queue: &str,
+) -> Result<QueueSummary, ServiceError> {
+    let (size, enqueued_last_hour, requested_last_hour) = queue_stats(conn, queue, RATE_WINDOW)
+        .await
+        .map_err(redis_error)?;
+    Ok(QueueSummary {
+        queue: queue.to_string(),
+        size,
+        enqueued_last_hour,
+        requested_last_hour,
+    })
+}
+
+fn check_queue(queue: &str) -> Result<&'static str, ServiceError> {
+    QUEUES
+        .into_iter()
+        .find(|known| *known == queue)
+        .ok_or_else(|| ServiceError::NotFound {
+            resource: String::from("Queue"),
+            detail: Some(format!("Queue {} not found", queue)),
+        })
+}
+
+fn check_members(members: &[String]) -> Result<(), ServiceError> {
+    if members.is_empty() || members.len() > MAX_MEMBERS {
+        return Err(ServiceError::InvalidInput {
+            field: String::from("members"),
+            reason: format!("members must contain between 1 and {} items", MAX_MEMBERS),
+        });
+    }
+    Ok(())
+}
+
+// 与 mcim-sync 消费时的格式一致，避免写入无法同步的成员
+fn check_member_format(queue: &str, members: &[String]) -> Result<(), ServiceError> {
+    let is_valid: fn(&str) -> bool = match queue {
+        CURSEFORGE_MODIDS | CURSEFORGE_FILEIDS => |m| m.parse::<i32>().is_ok_and(|id| id > 0),
+        CURSEFORGE_FINGERPRINTS => |m| m.parse::<u32>().is_ok(),
+        MODRINTH_HASHES_SHA1 => |m| is_hex(m, 40),
+        MODRINTH_HASHES_SHA512 => |m| is_hex(m, 128),
+        _ => is_modrinth_id,
+    };
+    match members.iter().find(|member| !is_valid(member)) {
+        Some(member) => Err(ServiceError::InvalidInput {
+            field: String::from("members"),
+            reason: format!("Invalid member {} for queue {}", member, queue),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn is_hex(value: &str, len: usize) -> bool {
+    value.len() == len && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn redis_error(e: RedisError) -> ServiceError {
+    ServiceError::ExternalServiceError {
+        service: String::from("Redis"),
+        message: e.to_string(),
+    }
+}
@@ -0,0 +1,284 @@
+use mongodb::Client as Mongo_Client;
+
+use crate::db::_redis::RedisPool;
+use crate::errors::ServiceError;
+use crate::models::curseforge::requests::SearchQuery as CurseforgeSearchQuery;
+use crate::models::curseforge::responses::SearchResponse as CurseforgeSearchResponse;
+use crate::models::modrinth::requests::SearchQuery as ModrinthSearchQuery;
+use crate::models::modrinth::responses::SearchResponse as ModrinthSearchResponse;
+use crate::models::search::requests::UnifiedSearchQuery;
+use crate::models::search::responses::{
+    Platform, PlatformError, UnifiedSearchHit, UnifiedSearchResponse,
+};
+use crate::services::curseforge::{CurseforgeService, parse_mod_loader_type};
+use crate::services::modrinth::ModrinthService;
+use crate::utils::app::AppState;
+
+// 两个平台都从第 0 条开始取 offset + limit 条再合并排序，因此需要限制窗口大小
+const MAX_SEARCH_WINDOW: i32 = 100;
+const CURSEFORGE_MAX_PAGE_SIZE: i32 = 50;
+// Curseforge ModsSearchSortField::TotalDownloads
+const CURSEFORGE_SORT_BY_DOWNLOADS: &str = "6";
+
+type PlatformResult = Result<(Vec<UnifiedSearchHit>, i64), ServiceError>;
+
+pub struct SearchService {
+    db: Mongo_Client,
+    redis: RedisPool,
+}
+
+impl SearchService {
+    pub fn new(db: Mongo_Client, redis: RedisPool) -> Self {
+        Self { db, redis }
+    }
+
+    pub async fn search(
+        &self,
+        state: &AppState,
+        query: &UnifiedSearchQuery,
+    ) -> Result<UnifiedSearchResponse, ServiceError> {
+        let offset = query.offset.unwrap_or(0);
+        let limit = query.limit.unwrap_or(20);
+        if offset < 0 || limit < 1 || offset + limit > MAX_SEARCH_WINDOW {
+            return Err(ServiceError::InvalidInput {
+                field: String::from("offset or limit"),
+                reason: format!(
+                    "offset must be non-negative, limit must be positive and offset + limit <= {}",
+                    MAX_SEARCH_WINDOW
+                ),
+            });
+        }
+        let platforms = parse_platforms(query.platforms.as_deref())?;
+        let window = offset + limit;
+
+        let (modrinth_result, curseforge_result) = futures::join!(
+            async {
+                if platforms.contains(&Platform::Modrinth) {
+                    Some(self.search_modrinth(state, query, window).await)
+                } else {
+                    None
+                }
+            },
+            async {
+                if platforms.contains(&Platform::Curseforge) {
+                    Some(self.search_curseforge(state, query, window).await)
+                } else {
+                    None
+                }
+            }
+        );
+
+        let mut hits = Vec::new();
+        let mut total_hits = 0;
+        let mut errors = Vec::new();
+        let mut succeeded = false;
+        for (platform, result) in [
+            (Platform::Modrinth, modrinth_result),
+            (Platform::Curseforge, curseforge_result),
+        ] {
+            match result {
+                Some(Ok((platform_hits, platform_total))) => {
+                    hits.extend(platform_hits);
+                    total_hits += platform_total;
+                    succeeded = true;
+                }
+                Some(Err(e)) => {
+                    log::warn!("Unified search failed on {:?}: {}", platform, e);
+                    errors.push(PlatformError {
+                        platform,
+                        message: e.to_string(),
+                    });
+                }
+                None => {}
+            }
+        }
+
+        if !succeeded {
+            return Err(ServiceError::ExternalServiceError {
+                service: String::from("Unified Search"),
+                message: errors
+                    .iter()
+                    .map(|e| format!("{:?}: {}", e.platform, e.message))
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            });
+        }
+
+        // 各平台结果已按下载量降序，稳定排序后即为按下载量交错的统一排名
+        hits.sort_by_key(|hit| std::cmp::Reverse(hit.downloads));
+        let hits = hits
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect();
+
+        Ok(UnifiedSearchResponse {
+            hits,
+            offset,
+            limit,
+            total_hits,
+            errors,
+        })
+    }
+
+    async fn search_modrinth(
+        &self,
+        state: &AppState,
+        query: &UnifiedSearchQuery,
+        window: i32,
+    ) -> PlatformResult {
+        let mut facets: Vec<Vec<String>> = Vec::new();
+        if let Some(game_version) = &query.game_version {
+            facets.push(vec![format!("versions:{}", game_version)]);
+        }
+        if let Some(loader) = &query.loader {
+            facets.push(vec![format!("categories:{}", loader.to_lowercase())]);
+        }
+
+        let modrinth_query = ModrinthSearchQuery {
+            query: query.query.clone(),
+            facets: if facets.is_empty() {
+                None
+            } else {
+                Some(serde_json::to_string(&facets)?)
+            },
+            offset: Some(0),
+            limit: Some(window),
+            index: Some(String::from("downloads")),
+        };
+
+        let service = ModrinthService::new(self.db.clone(), self.redis.clone());
+        let result = service
+            .search_by_mode(
+                &state.http_client,
+                &modrinth_query,
+                &state.modrinth_api_url,
+                state.modrinth_search_mode,
+            )
+            .await?;
+        let result: ModrinthSearchResponse = serde_json::from_value(result)?;
+
+        Ok((
+            result.hits.into_iter().map(Into::into).collect(),
+            result.total_hits as i64,
+        ))
+    }
+
+    async fn search_curseforge(
+        &self,
+        state: &AppState,
+        query: &UnifiedSearchQuery,
+        window: i32,
+    ) -> PlatformResult {
+        let mod_loader_type = match &query.loader {
+            Some(loader) => Some(parse_mod_loader_type(loader)?.to_string()),
+            None => None,
+        };
+
+        let service = CurseforgeService::new(self.db.clone(), self.redis.clone());
+        let mut hits = Vec::new();
+        let mut total_count = 0;
+        let mut index = 0;
+        // Curseforge 单页最多 50 条，窗口更大时需要翻页
+        while index < window {
+            let page_size = CURSEFORGE_MAX_PAGE_SIZE.min(window - index);
+            let curseforge_query = CurseforgeSearchQuery {
+                game_version: query.game_version.clone(),
+                search_filter: query.query.clone(),
+                sort_field: Some(String::from(CURSEFORGE_SORT_BY_DOWNLOADS)),
+                sort_order: Some(String::from("desc")),
+                mod_loader_type: mod_loader_type.clone(),
+                index: Some(index),
+                page_size: Some(page_size),
+                ..Default::default()
+            };
+
+            let result = service
+                .search_mods_by_mode(
+                    &state.http_client,
+                    &curseforge_query,
+                    &state.curseforge_api_url,
+                    &state.curseforge_api_key,
+                    state.curseforge_search_mode,
+                )
+                .await?;
+            let result: CurseforgeSearchResponse = serde_json::from_value(result)?;
+
+            total_count = result.pagination.total_count;
+            let page_len = result.data.len() as i32;
+            hits.extend(result.data.into_iter().map(UnifiedSearchHit::from));
+            if page_len < page_size {
+                break;
+            }
+            index += page_size;
+        }
+
+        Ok((hits, total_count as i64))
+    }
+}
+
+fn parse_platforms(platforms: Option<&str>) -> Result<Vec<Platform>, ServiceError> {
+    let Some(platforms) = platforms else {
+        return Ok(vec![Platform::Modrinth, Platform::Curseforge]);
+    };
+
+    let mut parsed = Vec::new();
+    for platform in platforms
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+    {
+        let platform = match platform.to_lowercase().as_str() {
+            "modrinth" => Platform::Modrinth,
+            "curseforge" => Platform::Curseforge,
+            _ => {
+                return Err(ServiceError::InvalidInput {
+                    field: String::from("platforms"),
+                    reason: format!("Unknown platform {}", platform),
+                });
+            }
+        };
+        if !parsed.contains(&platform) {
+            parsed.push(platform);
+        }
+    }
+
+    if parsed.is_empty() {
+        return Err(ServiceError::InvalidInput {
+            field: String::from("platforms"),
+            reason: String::from("At least one platform is required"),
+        });
+    }
+    Ok(parsed)
+}
+
+/// 规范化后的查询字符串，作为缓存 key，使等价的请求共用缓存
+pub fn normalize_query(query: &UnifiedSearchQuery) -> Result<String, ServiceError> {
+    let mut platforms: Vec<&str> = parse_platforms(query.platforms.as_deref())?
+        .into_iter()
+        .map(|platform| match platform {
+            Platform::Modrinth => "modrinth",
+            Platform::Curseforge => "curseforge",
+        })
+        .collect();
+    platforms.sort_unstable();
+
+    let normalize = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_lowercase)
+            .unwrap_or_default()
+    };
+
+    Ok(format!(
+        "query={:?}&platforms={}&game_version={:?}&loader={:?}&offset={}&limit={}",
+        normalize(&query.query),
+        platforms.join(","),
+        normalize(&query.game_version),
+        normalize(&query.loader),
+        query.offset.unwrap_or(0),
+        query.limit.unwrap_or(20)
+    ))
+}
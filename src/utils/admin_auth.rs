@@ -0,0 +1,86 @@
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header;
+use actix_web::middleware::Next;
+use actix_web::{Error, HttpMessage, web};
+
+use crate::errors::ApiError;
+use crate::models::admin::entities::AdminRole;
+use crate::services::admin_token::AdminTokenService;
+use crate::utils::app::AppState;
+
+/// 通过认证的管理员，写入 request extensions 供 handler 使用
+#[derive(Debug, Clone)]
+pub struct AdminIdentity {
+    pub name: String,
+    pub role: AdminRole,
+}
+
+fn bearer_token(req: &ServiceRequest) -> Option<&str> {
+    req.headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim)
+}
+
+/// 请求是否携带有效的 admin token，任意角色均可
+pub async fn is_admin_request(req: &ServiceRequest) -> bool {
+    let (Some(token), Some(state)) = (bearer_token(req), req.app_data::<web::Data<AppState>>())
+    else {
+        return false;
+    };
+    match AdminTokenService::new(state.db.clone())
+        .authenticate(token)
+        .await
+    {
+        Ok(record) => record.is_some(),
+        Err(e) => {
+            log::error!("Admin token check error: {}", e);
+            false
+        }
+    }
+}
+
+/// `/admin` 下的接口需要携带 `Authorization: Bearer <token>`
+///
+/// token 由 `mcim-rust-api token create` 生成，operator 只能调用只读接口
+pub async fn require_admin(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let Some(token) = bearer_token(&req) else {
+        return Err(ApiError::Unauthorized("Missing admin token".to_string()).into());
+    };
+
+    let Some(state) = req.app_data::<web::Data<AppState>>() else {
+        return Err(ApiError::InternalServerError("App state is missing".to_string()).into());
+    };
+
+    let service = AdminTokenService::new(state.db.clone());
+    let record = service
+        .authenticate(token)
+        .await
+        .map_err(ApiError::from)?
+        .ok_or_else(|| ApiError::Unauthorized("Invalid admin token".to_string()))?;
+
+    if record.role == AdminRole::Operator && !req.method().is_safe() {
+        return Err(ApiError::Forbidden(format!("Token {} is read-only", record.name)).into());
+    }
+
+    if !req.method().is_safe() {
+        log::info!(
+            "Admin {} ({:?}) {} {}",
+            record.name,
+            record.role,
+            req.method(),
+            req.path()
+        );
+    }
+
+    req.extensions_mut().insert(AdminIdentity {
+        name: record.name,
+        role: record.role,
+    });
+    next.call(req).await
+}
@@ -1,15 +1,44 @@
-use redis::aio::MultiplexedConnection;
 use reqwest::Client;
 use std::env;
-use std::sync::Arc;
+
+use crate::db::_redis::RedisPool;
+use crate::utils::user_agent_filter::UserAgentFilter;
+
+// 搜索模式：upstream 只代理官方 API，fallback 官方失败时回退到本地搜索，local 只使用本地搜索
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchMode {
+    Upstream,
+    Fallback,
+    Local,
+}
+
+impl SearchMode {
+    fn from_env(key: &str) -> Self {
+        match env::var(key)
+            .unwrap_or_else(|_| "upstream".to_string())
+            .to_lowercase()
+            .as_str()
+        {
+            "upstream" => SearchMode::Upstream,
+            "fallback" => SearchMode::Fallback,
+            "local" => SearchMode::Local,
+            other => {
+                log::warn!("Unknown {} value {}, using upstream", key, other);
+                SearchMode::Upstream
+            }
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct AppState {
     pub db: mongodb::Client,
-    pub redis_pool: Arc<MultiplexedConnection>,
+    pub redis_pool: RedisPool,
     pub curseforge_api_url: String,
     pub modrinth_api_url: String,
     pub curseforge_api_key: String,
+    pub curseforge_search_mode: SearchMode,
+    pub modrinth_search_mode: SearchMode,
     pub file_cdn_enabled: bool,
     pub curseforge_file_cdn_url: String,
     pub curseforge_file_cdn_fallback_url: String,
@@ -24,12 +53,10 @@ pub struct AppState {
     pub modrinth_avatar_cdn_fallback_url: String,
     pub modrinth_avatar_cdn_primary_percentage: u8,
     pub http_client: Client, // 共享的 HTTP 客户端
+    pub user_agent_filter: UserAgentFilter,
 }
 
-pub fn build_app_state(
-    mongo_client: mongodb::Client,
-    redis_pool: std::sync::Arc<redis::aio::MultiplexedConnection>,
-) -> AppState {
+pub fn build_app_state(mongo_client: mongodb::Client, redis_pool: RedisPool) -> AppState {
     AppState {
         db: mongo_client,
         redis_pool,
@@ -38,6 +65,8 @@ pub fn build_app_state(
         modrinth_api_url: env::var("MODRINTH_API_URL")
             .unwrap_or_else(|_| "https://api.modrinth.com".to_string()),
         curseforge_api_key: env::var("CURSEFORGE_API_KEY").unwrap_or_else(|_| "".to_string()),
+        curseforge_search_mode: SearchMode::from_env("CURSEFORGE_SEARCH_MODE"),
+        modrinth_search_mode: SearchMode::from_env("MODRINTH_SEARCH_MODE"),
         file_cdn_enabled: env::var("FILE_CDN_ENABLED")
             .unwrap_or_else(|_| "true".to_string())
             .parse::<bool>()
@@ -77,5 +106,6 @@ pub fn build_app_state(
             .parse::<u8>()
             .unwrap_or(100),
         http_client: Client::new(), // 创建一个共享的 HTTP 客户端
+        user_agent_filter: UserAgentFilter::from_env(),
     }
 }
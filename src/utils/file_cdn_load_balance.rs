@@ -1,4 +1,7 @@
 use rand::Rng;
+use std::fmt::Display;
+
+use crate::utils::app::AppState;
 
 /// 根据百分比选择 URL
 ///
@@ -20,3 +23,69 @@ pub fn select_cdn_endpoint(
         fallback_endpoint.to_string()
     }
 }
+
+const MODRINTH_CDN_ORIGIN: &str = "https://cdn.modrinth.com";
+
+/// Modrinth 文件 CDN endpoint，未启用 file_cdn 时使用回退地址
+pub fn modrinth_file_endpoint(state: &AppState) -> String {
+    if !state.file_cdn_enabled {
+        return state.modrinth_file_cdn_fallback_url.clone();
+    }
+    select_cdn_endpoint(
+        state.modrinth_file_cdn_url.clone(),
+        state.modrinth_file_cdn_fallback_url.clone(),
+        state.modrinth_cdn_primary_percentage,
+    )
+}
+
+/// Curseforge 文件 CDN endpoint，未启用 file_cdn 时使用回退地址
+pub fn curseforge_file_endpoint(state: &AppState) -> String {
+    if !state.file_cdn_enabled {
+        return state.curseforge_file_cdn_fallback_url.clone();
+    }
+    select_cdn_endpoint(
+        state.curseforge_file_cdn_url.clone(),
+        state.curseforge_file_cdn_fallback_url.clone(),
+        state.curseforge_cdn_primary_percentage,
+    )
+}
+
+/// 将 cdn.modrinth.com 的文件 URL 改写为 file_cdn 地址，其他来源原样返回
+pub fn rewrite_modrinth_file_url(state: &AppState, url: &str) -> String {
+    match url.strip_prefix(MODRINTH_CDN_ORIGIN) {
+        Some(path) => format!("{}{}", modrinth_file_endpoint(state), path),
+        None => url.to_string(),
+    }
+}
+
+/// Modrinth 文件的 file_cdn 地址
+pub fn modrinth_file_url(
+    state: &AppState,
+    project_id: &str,
+    version_id: &str,
+    file_name: &str,
+) -> String {
+    format!(
+        "{}/data/{}/versions/{}/{}",
+        modrinth_file_endpoint(state),
+        project_id,
+        version_id,
+        file_name
+    )
+}
+
+/// Curseforge 文件的 file_cdn 地址，路径为 /files/{id / 1000}/{id % 1000}/{fileName}
+pub fn curseforge_file_url(
+    state: &AppState,
+    file_id1: impl Display,
+    file_id2: impl Display,
+    file_name: &str,
+) -> String {
+    format!(
+        "{}/files/{}/{}/{}",
+        curseforge_file_endpoint(state),
+        file_id1,
+        file_id2,
+        urlencoding::encode(file_name)
+    )
+}
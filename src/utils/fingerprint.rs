@@ -0,0 +1,95 @@
+use sha1::Sha1;
+use sha2::{Digest, Sha512};
+
+// Curseforge 计算指纹前会去掉这些空白字节
+const CURSEFORGE_IGNORED_BYTES: [u8; 4] = [b'\t', b'\n', b'\r', b' '];
+const MURMUR2_SEED: u32 = 1;
+
+pub struct FileHashes {
+    pub fingerprint: u32,
+    pub sha1: String,
+    pub sha512: String,
+}
+
+impl FileHashes {
+    pub fn compute(data: &[u8]) -> Self {
+        Self {
+            fingerprint: curseforge_fingerprint(data),
+            sha1: hex::encode(Sha1::digest(data)),
+            sha512: hex::encode(Sha512::digest(data)),
+        }
+    }
+}
+
+/// Curseforge 指纹：去除空白字节后的 MurmurHash2 (seed = 1)
+pub fn curseforge_fingerprint(data: &[u8]) -> u32 {
+    let normalized: Vec<u8> = data
+        .iter()
+        .copied()
+        .filter(|b| !CURSEFORGE_IGNORED_BYTES.contains(b))
+        .collect();
+    murmur2(&normalized, MURMUR2_SEED)
+}
+
+fn murmur2(data: &[u8], seed: u32) -> u32 {
+    const M: u32 = 0x5bd1e995;
+    const R: u32 = 24;
+
+    let mut h = seed ^ data.len() as u32;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(M);
+        k ^= k >> R;
+        k = k.wrapping_mul(M);
+        h = h.wrapping_mul(M);
+        h ^= k;
+    }
+
+    let tail = chunks.remainder();
+    if tail.len() >= 3 {
+        h ^= (tail[2] as u32) << 16;
+    }
+    if tail.len() >= 2 {
+        h ^= (tail[1] as u32) << 8;
+    }
+    if !tail.is_empty() {
+        h ^= tail[0] as u32;
+        h = h.wrapping_mul(M);
+    }
+
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^= h >> 15;
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curseforge_fingerprint() {
+        assert_eq!(curseforge_fingerprint(b""), 1540447798);
+        assert_eq!(
+            curseforge_fingerprint(b"The quick brown fox jumps over the lazy dog"),
+            3751777527
+        );
+    }
+
+    #[test]
+    fn test_curseforge_fingerprint_ignores_whitespace() {
+        assert_eq!(curseforge_fingerprint(b"hello world\n"), 2824650221);
+        assert_eq!(
+            curseforge_fingerprint(b"hello world\n"),
+            curseforge_fingerprint(b"hello\tworld\r")
+        );
+    }
+
+    #[test]
+    fn test_file_hashes() {
+        let hashes = FileHashes::compute(b"abc");
+        assert_eq!(hashes.sha1, "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert!(hashes.sha512.starts_with("ddaf35a193617aba"));
+    }
+}
@@ -1,3 +1,7 @@
+pub mod admin_auth;
 pub mod app;
 pub mod file_cdn_load_balance;
+pub mod fingerprint;
 pub mod redis_cache;
+pub mod search;
+pub mod user_agent_filter;
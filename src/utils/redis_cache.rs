@@ -1,73 +1,317 @@
+use crate::db::_redis::RedisPool;
+use crate::db::sync_queue::track_enqueue;
 use crate::errors::ApiError;
+use crate::utils::admin_auth::is_admin_request;
+use crate::utils::app::AppState;
 
-use actix_web::HttpResponse;
+use actix_web::body::{BoxBody, MessageBody, to_bytes};
+use actix_web::dev::{Payload, Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
+use actix_web::error::PayloadError;
+use actix_web::http::StatusCode;
+use actix_web::http::header::{self, HeaderName, HeaderValue};
+use actix_web::{Error, HttpMessage, HttpResponse, web};
+use bytes::BytesMut;
 use futures::Future;
+use futures::future::{BoxFuture, FutureExt, LocalBoxFuture, Shared};
+use futures::stream::StreamExt;
 use redis::AsyncCommands;
 use redis::aio::MultiplexedConnection;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::{Ready, ready};
 use std::pin::Pin;
-use std::sync::Arc;
+use std::rc::Rc;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+type HandlerFuture<T> = Pin<Box<dyn Future<Output = Result<T, ApiError>> + Send + 'static>>;
+type SharedHandler = Shared<BoxFuture<'static, Result<String, ApiError>>>;
+
+// 进程内正在执行的 handler，相同 key 的请求共享同一个 future
+static IN_FLIGHT: LazyLock<Mutex<HashMap<String, SharedHandler>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// 跨实例的 Redis 锁，持有锁的实例负责回源，其余实例短暂等待缓存写入
+const LOCK_TTL_MS: u64 = 10_000;
+const LOCK_WAIT: Duration = Duration::from_secs(3);
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+// 后台刷新失败后，在该间隔内继续返回旧数据而不再回源
+const REVALIDATE_RETRY_INTERVAL: u64 = 30;
+
+// 缓存以 hash 存储，stored_at 用于判断软过期，failed_at 记录最近一次刷新失败
+const BODY_FIELD: &str = "body";
+const STORED_AT_FIELD: &str = "stored_at";
+const FAILED_AT_FIELD: &str = "failed_at";
+
+const RELEASE_LOCK_SCRIPT: &str = r#"
+if redis.call("GET", KEYS[1]) == ARGV[1] then
+    return redis.call("DEL", KEYS[1])
+end
+return 0
+"#;
+
+// 只标记已存在的缓存，避免创建没有过期时间的 hash
+const MARK_FAILED_SCRIPT: &str = r#"
+if redis.call("EXISTS", KEYS[1]) == 1 then
+    return redis.call("HSET", KEYS[1], ARGV[1], ARGV[2])
+end
+return 0
+"#;
+
+/// 缓存的软过期与硬过期时间（秒）
+///
+/// 超过 `soft` 后返回旧数据并在后台刷新，超过 `hard` 后缓存被删除
+#[derive(Clone, Copy, Debug)]
+pub struct CacheTtl {
+    pub soft: u64,
+    pub hard: u64,
+}
+
+impl CacheTtl {
+    pub fn new(soft: u64, hard: u64) -> Self {
+        Self {
+            soft,
+            hard: hard.max(soft),
+        }
+    }
+}
 
 /// 通用缓存包装器
 pub async fn cacheable_json<T, F>(
-    pool: Arc<MultiplexedConnection>,
+    pool: RedisPool,
     key: String,
-    ttl: u64,
+    ttl: CacheTtl,
+    handler: F,
+) -> Result<HttpResponse, ApiError>
+where
+    T: Serialize + for<'de> Deserialize<'de> + Send + 'static,
+    F: FnOnce() -> HandlerFuture<T>,
+{
+    cacheable_json_if(pool, key, ttl, |_| true, handler).await
+}
+
+/// 同 `cacheable_json`，`should_cache` 返回 false 的结果照常返回但不写入缓存
+///
+/// 不写入时视为一次刷新失败，已有的旧数据继续以 STALE 返回
+pub async fn cacheable_json_if<T, F>(
+    pool: RedisPool,
+    key: String,
+    ttl: CacheTtl,
+    should_cache: fn(&T) -> bool,
     handler: F,
 ) -> Result<HttpResponse, ApiError>
 where
     T: Serialize + for<'de> Deserialize<'de> + Send + 'static,
-    F: FnOnce() -> Pin<Box<dyn Future<Output = Result<T, ApiError>> + Send + 'static>>,
+    F: FnOnce() -> HandlerFuture<T>,
 {
-    let mut conn = pool.as_ref().clone();
-    let mut use_cache = true;
+    // Redis 不可用时直接执行 handler
+    let Some(mut conn) = pool.get() else {
+        let json_result = single_flight(None, key, ttl, should_cache, handler).await?;
+        return Ok(json_response(json_result, "MISS", None));
+    };
 
     // 检查缓存是否存在
-    match conn.get::<_, Option<String>>(&key).await {
-        Ok(Some(cached)) => {
-            return Ok(HttpResponse::Ok()
-                .content_type("application/json")
-                .body(cached));
+    match conn
+        .hget::<_, _, (Option<String>, Option<u64>, Option<u64>)>(
+            &key,
+            &[BODY_FIELD, STORED_AT_FIELD, FAILED_AT_FIELD],
+        )
+        .await
+    {
+        Ok((Some(cached), stored_at, failed_at)) => {
+            let now = unix_timestamp();
+            if now.saturating_sub(stored_at.unwrap_or(0)) < ttl.soft {
+                return Ok(json_response(cached, "HIT", None));
+            }
+
+            // 已软过期，先返回旧数据，刷新失败后短时间内不再回源
+            let recently_failed =
+                failed_at.is_some_and(|t| now.saturating_sub(t) < REVALIDATE_RETRY_INTERVAL);
+            if !recently_failed {
+                let refresh = single_flight(Some(conn), key, ttl, should_cache, handler);
+                actix_web::rt::spawn(async move {
+                    let _ = refresh.await;
+                });
+            }
+
+            let warning = if failed_at.is_some() {
+                "111 - \"Revalidation Failed\""
+            } else {
+                "110 - \"Response is Stale\""
+            };
+            return Ok(json_response(cached, "STALE", Some(warning)));
         }
-        Ok(None) => {} // 没有缓存，继续执行
+        Ok(_) => {} // 没有缓存，继续执行
         Err(e) => {
             log::error!("Redis get error: {}", e);
-            use_cache = false;
+            let json_result = single_flight(None, key, ttl, should_cache, handler).await?;
+            return Ok(json_response(json_result, "MISS", None));
+        }
+    }
+
+    let json_result = single_flight(Some(conn), key, ttl, should_cache, handler).await?;
+    Ok(json_response(json_result, "MISS", None))
+}
+
+fn json_response(body: String, cache_status: &str, warning: Option<&str>) -> HttpResponse {
+    let mut builder = HttpResponse::Ok();
+    builder
+        .content_type("application/json")
+        .insert_header((CACHE_STATUS_HEADER, cache_status));
+    if let Some(warning) = warning {
+        builder.insert_header((header::WARNING, warning));
+    }
+    builder.body(body)
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// 同一个 key 只执行一次 handler
+fn single_flight<T, F>(
+    conn: Option<MultiplexedConnection>,
+    key: String,
+    ttl: CacheTtl,
+    should_cache: fn(&T) -> bool,
+    handler: F,
+) -> SharedHandler
+where
+    T: Serialize + Send + 'static,
+    F: FnOnce() -> HandlerFuture<T>,
+{
+    let mut in_flight = IN_FLIGHT.lock().unwrap();
+    if let Some(shared) = in_flight.get(&key) {
+        return shared.clone();
+    }
+
+    let shared = fill_cache(conn, key.clone(), ttl, should_cache, handler())
+        .boxed()
+        .shared();
+    in_flight.insert(key, shared.clone());
+    shared
+}
+
+async fn fill_cache<T>(
+    mut conn: Option<MultiplexedConnection>,
+    key: String,
+    ttl: CacheTtl,
+    should_cache: fn(&T) -> bool,
+    handler: HandlerFuture<T>,
+) -> Result<String, ApiError>
+where
+    T: Serialize + Send + 'static,
+{
+    let lock_key = format!("lock:{}", key);
+    let token = rand::random::<u64>().to_string();
+    let mut locked = false;
+
+    if let Some(conn) = conn.as_mut() {
+        match redis::cmd("SET")
+            .arg(&lock_key)
+            .arg(&token)
+            .arg("NX")
+            .arg("PX")
+            .arg(LOCK_TTL_MS)
+            .query_async::<Option<String>>(conn)
+            .await
+        {
+            Ok(Some(_)) => locked = true,
+            Ok(None) => {
+                // 其他实例正在回源，等待其写入缓存，超时后自行回源
+                if let Some(cached) = wait_for_cache(conn, &key).await {
+                    IN_FLIGHT.lock().unwrap().remove(&key);
+                    return Ok(cached);
+                }
+            }
+            Err(e) => log::error!("Redis lock error: {}", e),
         }
     }
 
-    // 执行原始逻辑
-    match handler().await {
-        Ok(data) => {
-            // 只有成功时才缓存和返回
-            let json_result: String = serde_json::to_string(&data).map_err(|e| {
+    // 执行原始逻辑，错误时直接返回，不缓存
+    let result = handler.await.and_then(|data| {
+        let cache = should_cache(&data);
+        serde_json::to_string(&data)
+            .map(|json_result| (json_result, cache))
+            .map_err(|e| {
                 log::error!("JSON serialize error: {}", e);
                 ApiError::InternalServerError("Serialization error".to_string())
-            })?;
+            })
+    });
 
-            if use_cache {
-                if let Err(e) = conn
-                    .set_ex::<&str, &str, ()>(&key, json_result.as_str(), ttl)
+    if let Some(conn) = conn.as_mut() {
+        let stored = match &result {
+            Ok((json_result, true)) => {
+                redis::pipe()
+                    .atomic()
+                    .del(&key)
+                    .hset_multiple(
+                        &key,
+                        &[
+                            (BODY_FIELD, json_result.clone()),
+                            (STORED_AT_FIELD, unix_timestamp().to_string()),
+                        ],
+                    )
+                    .expire(&key, ttl.hard as i64)
+                    .query_async::<()>(conn)
                     .await
-                {
-                    log::error!("Redis set error: {}", e);
-                }
             }
-
-            Ok(HttpResponse::Ok()
-                .content_type("application/json")
-                .body(json_result))
+            Ok((_, false)) | Err(_) => {
+                redis::Script::new(MARK_FAILED_SCRIPT)
+                    .key(&key)
+                    .arg(FAILED_AT_FIELD)
+                    .arg(unix_timestamp())
+                    .invoke_async::<()>(conn)
+                    .await
+            }
+        };
+        if let Err(e) = stored {
+            log::error!("Redis set error: {}", e);
         }
-        Err(e) => {
-            // 错误时直接返回，不缓存
-            Err(e)
+    }
+
+    if locked
+        && let Some(conn) = conn.as_mut()
+        && let Err(e) = redis::Script::new(RELEASE_LOCK_SCRIPT)
+            .key(&lock_key)
+            .arg(&token)
+            .invoke_async::<i32>(conn)
+            .await
+    {
+        log::error!("Redis unlock error: {}", e);
+    }
+
+    IN_FLIGHT.lock().unwrap().remove(&key);
+    result.map(|(json_result, _)| json_result)
+}
+
+async fn wait_for_cache(conn: &mut MultiplexedConnection, key: &str) -> Option<String> {
+    let deadline = Instant::now() + LOCK_WAIT;
+    while Instant::now() < deadline {
+        actix_web::rt::time::sleep(LOCK_POLL_INTERVAL).await;
+        match conn.hget::<_, _, Option<String>>(key, BODY_FIELD).await {
+            Ok(Some(cached)) => return Some(cached),
+            Ok(None) => {}
+            Err(e) => {
+                log::error!("Redis get error: {}", e);
+                return None;
+            }
         }
     }
+    None
 }
 
 use md5::compute;
 
 pub fn create_key(method: String, path: String, query: String) -> String {
+    format!("cache:{:x}", key_digest(&method, &path, &query))
+}
+
+fn key_digest(method: &str, path: &str, query: &str) -> md5::Digest {
     let base_key = if query.is_empty() {
         format!("{}:{}", method, path)
     } else {
@@ -75,7 +319,196 @@ pub fn create_key(method: String, path: String, query: String) -> String {
     };
 
     // Hash the key using MD5
-    let result = compute(base_key.as_bytes());
-    // Convert to hex string
-    format!("cache:{:x}", result)
+    compute(base_key.as_bytes())
+}
+
+// 实体查询缓存较短，避免同步后长时间返回旧数据
+pub const ROUTE_CACHE_TTL: u64 = 600;
+// 标签、游戏版本等几乎不变的数据
+pub const TAG_CACHE_TTL: u64 = 3600;
+// 代理官方 API 的搜索，1 小时后后台刷新，上游故障时最多返回 1 天前的数据
+pub const SEARCH_CACHE_TTL: CacheTtl = CacheTtl {
+    soft: 3600,
+    hard: 86400,
+};
+
+// 携带该请求头及有效的 admin token 时跳过缓存读取，直接执行 handler 并刷新缓存
+pub const CACHE_BYPASS_HEADER: &str = "X-Cache-Bypass";
+pub const CACHE_STATUS_HEADER: &str = "x-cache";
+
+// 路由缓存以 string 存储，与 cacheable_json 的 hash 使用不同前缀，避免 WRONGTYPE
+const ROUTE_CACHE_KEY_PREFIX: &str = "cache:route:v1";
+
+// 与 actix 默认 JsonConfig 的 2MB 限制保持一致
+const MAX_CACHE_KEY_BODY_SIZE: usize = 2 * 1024 * 1024;
+
+/// 根据 method、规范化后的 path、排序后的 query 和 body 哈希生成缓存 key
+pub fn create_request_key(method: &str, path: &str, query: &str, body: &[u8]) -> String {
+    let path = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("/");
+
+    let mut pairs: Vec<&str> = query.split('&').filter(|pair| !pair.is_empty()).collect();
+    pairs.sort_unstable();
+    let mut query = pairs.join("&");
+
+    if !body.is_empty() {
+        if !query.is_empty() {
+            query.push('&');
+        }
+        query.push_str(&format!("body={:x}", compute(body)));
+    }
+
+    format!(
+        "{}:{:x}",
+        ROUTE_CACHE_KEY_PREFIX,
+        key_digest(method, &format!("/{}", path), &query)
+    )
+}
+
+/// 路由级缓存中间件，只缓存 200 且没有 ID 写入同步队列的 JSON 响应
+///
+/// ```ignore
+/// #[get("/mods/{mod_id}", wrap = "RouteCache::new(ROUTE_CACHE_TTL)")]
+/// ```
+#[derive(Clone, Copy)]
+pub struct RouteCache {
+    ttl: u64,
+}
+
+impl RouteCache {
+    pub fn new(ttl: u64) -> Self {
+        Self { ttl }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RouteCache
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = Error;
+    type Transform = RouteCacheMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RouteCacheMiddleware {
+            service: Rc::new(service),
+            ttl: self.ttl,
+        }))
+    }
+}
+
+pub struct RouteCacheMiddleware<S> {
+    service: Rc<S>,
+    ttl: u64,
+}
+
+impl<S, B> Service<ServiceRequest> for RouteCacheMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let ttl = self.ttl;
+
+        Box::pin(async move {
+            let Some(mut conn) = req
+                .app_data::<web::Data<AppState>>()
+                .and_then(|state| state.redis_pool.get())
+            else {
+                return service.call(req).await.map(|res| res.map_into_boxed_body());
+            };
+
+            // 读取 body 计算哈希后再放回，供 handler 的提取器使用
+            let mut payload = req.take_payload();
+            let mut body = BytesMut::new();
+            while let Some(chunk) = payload.next().await {
+                let chunk = chunk?;
+                if body.len() + chunk.len() > MAX_CACHE_KEY_BODY_SIZE {
+                    return Err(PayloadError::Overflow.into());
+                }
+                body.extend_from_slice(&chunk);
+            }
+            let body = body.freeze();
+
+            let key =
+                create_request_key(req.method().as_str(), req.path(), req.query_string(), &body);
+            req.set_payload(Payload::from(body));
+
+            let mut use_cache = true;
+
+            // 匿名请求忽略该请求头，避免被用来绕过缓存直接查询数据库
+            let bypass =
+                req.headers().contains_key(CACHE_BYPASS_HEADER) && is_admin_request(&req).await;
+
+            if !bypass {
+                match conn.get::<_, Option<Vec<u8>>>(&key).await {
+                    Ok(Some(cached)) => {
+                        let res = HttpResponse::Ok()
+                            .content_type("application/json")
+                            .insert_header((CACHE_STATUS_HEADER, "HIT"))
+                            .body(cached);
+                        return Ok(req.into_response(res));
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        log::error!("Redis get error: {}", e);
+                        use_cache = false;
+                    }
+                }
+            }
+
+            // 有 ID 写入同步队列的响应不缓存，否则命中缓存后不再统计这些 ID 的请求次数
+            let (res, enqueued) = track_enqueue(service.call(req)).await;
+            let res = res?;
+            if enqueued {
+                use_cache = false;
+            }
+
+            let is_json = res
+                .headers()
+                .get(header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.starts_with("application/json"));
+            if res.status() != StatusCode::OK || !is_json {
+                return Ok(res.map_into_boxed_body());
+            }
+
+            let (req, res) = res.into_parts();
+            let (mut res, body) = res.into_parts();
+            let body = to_bytes(body).await.map_err(|e| {
+                log::error!("Read response body error: {}", e.into());
+                ApiError::InternalServerError("Read response body error".to_string())
+            })?;
+
+            if use_cache
+                && let Err(e) = conn
+                    .set_ex::<&str, &[u8], ()>(&key, body.as_ref(), ttl)
+                    .await
+            {
+                log::error!("Redis set error: {}", e);
+            }
+
+            res.headers_mut().insert(
+                HeaderName::from_static(CACHE_STATUS_HEADER),
+                HeaderValue::from_static("MISS"),
+            );
+            Ok(ServiceResponse::new(
+                req,
+                res.set_body(body).map_into_boxed_body(),
+            ))
+        })
+    }
 }
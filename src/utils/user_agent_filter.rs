@@ -0,0 +1,335 @@
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::{StatusCode, header};
+use actix_web::middleware::Next;
+use actix_web::{Error, HttpResponse, web};
+use futures::stream::TryStreamExt;
+use prometheus::{IntCounterVec, Opts};
+use std::env;
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::Duration;
+
+use crate::db::database::get_database_name;
+use crate::models::ErrorResponse;
+use crate::models::user_agent::entities::{UserAgentAction, UserAgentRule};
+use crate::utils::app::AppState;
+
+const USER_AGENT_RULE_COLLECTION: &str = "user_agent_rules";
+const DEFAULT_RELOAD_INTERVAL: u64 = 60;
+const DEFAULT_REJECT_MESSAGE: &str = "User-Agent is not allowed, please submit your launcher's User-Agent at https://github.com/mcmod-info-mirror/mcim-rust-api/issues/12";
+
+// 不受 UA 过滤影响的路径，避免运维接口被拦截
+const EXEMPT_PATH_PREFIXES: [&str; 1] = ["/admin"];
+
+pub static USER_AGENT_RULE_HITS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    IntCounterVec::new(
+        Opts::new(
+            "user_agent_rule_hits",
+            "Number of requests matched by each User-Agent rule",
+        )
+        .namespace("api"),
+        &["pattern", "action"],
+    )
+    .unwrap()
+});
+
+pub static USER_AGENT_REJECTED: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    IntCounterVec::new(
+        Opts::new(
+            "user_agent_rejected",
+            "Number of requests rejected by the User-Agent filter, monitor mode only counts",
+        )
+        .namespace("api"),
+        &["mode", "reason"],
+    )
+    .unwrap()
+});
+
+// off 不过滤，monitor 只记录指标与日志，enforce 拒绝请求
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserAgentFilterMode {
+    Off,
+    Monitor,
+    Enforce,
+}
+
+impl UserAgentFilterMode {
+    fn from_env(key: &str) -> Self {
+        match env::var(key)
+            .unwrap_or_else(|_| "off".to_string())
+            .to_lowercase()
+            .as_str()
+        {
+            "off" => UserAgentFilterMode::Off,
+            "monitor" => UserAgentFilterMode::Monitor,
+            "enforce" => UserAgentFilterMode::Enforce,
+            other => {
+                log::warn!("Unknown {} value {}, using off", key, other);
+                UserAgentFilterMode::Off
+            }
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            UserAgentFilterMode::Off => "off",
+            UserAgentFilterMode::Monitor => "monitor",
+            UserAgentFilterMode::Enforce => "enforce",
+        }
+    }
+}
+
+/// 过滤结果，`pattern` 为命中的规则
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserAgentDecision {
+    Allowed { pattern: Option<String> },
+    Denied { pattern: String },
+    Unlisted,
+}
+
+#[derive(Debug)]
+struct CompiledRule {
+    pattern: String,
+    action: UserAgentAction,
+    // 按 `*` 切分后的小写片段
+    parts: Vec<String>,
+}
+
+impl CompiledRule {
+    fn new(rule: UserAgentRule) -> Self {
+        let parts = rule
+            .pattern
+            .to_lowercase()
+            .split('*')
+            .map(str::to_string)
+            .collect();
+        Self {
+            pattern: rule.pattern,
+            action: rule.action,
+            parts,
+        }
+    }
+
+    fn matches(&self, user_agent: &str) -> bool {
+        glob_match(&self.parts, &user_agent.to_lowercase())
+    }
+}
+
+// 首段须匹配开头，末段须匹配结尾，中间各段按顺序出现
+fn glob_match(parts: &[String], text: &str) -> bool {
+    let (first, rest) = match parts.split_first() {
+        Some(split) => split,
+        None => return text.is_empty(),
+    };
+    let Some(mut remaining) = text.strip_prefix(first.as_str()) else {
+        return false;
+    };
+    let Some((last, middle)) = rest.split_last() else {
+        return remaining.is_empty();
+    };
+    for part in middle {
+        match remaining.find(part.as_str()) {
+            Some(index) => remaining = &remaining[index + part.len()..],
+            None => return false,
+        }
+    }
+    remaining.ends_with(last.as_str())
+}
+
+/// UA 白名单/黑名单
+///
+/// 先匹配 deny 规则；存在 allow 规则时，未命中任何 allow 规则的 UA 同样会被拒绝。
+/// 规则由后台任务定期从 MongoDB 或文件重新加载，加载失败时保留原有规则
+#[derive(Clone)]
+pub struct UserAgentFilter {
+    pub mode: UserAgentFilterMode,
+    pub reject_status: StatusCode,
+    pub reject_message: String,
+    rule_file: Option<String>,
+    reload_interval: Duration,
+    rules: Arc<RwLock<Arc<Vec<CompiledRule>>>>,
+}
+
+impl UserAgentFilter {
+    pub fn new(mode: UserAgentFilterMode, rules: Vec<UserAgentRule>) -> Self {
+        let filter = Self {
+            mode,
+            reject_status: StatusCode::FORBIDDEN,
+            reject_message: DEFAULT_REJECT_MESSAGE.to_string(),
+            rule_file: None,
+            reload_interval: Duration::from_secs(DEFAULT_RELOAD_INTERVAL),
+            rules: Arc::new(RwLock::new(Arc::new(Vec::new()))),
+        };
+        filter.set_rules(rules);
+        filter
+    }
+
+    pub fn from_env() -> Self {
+        let mut filter = Self::new(UserAgentFilterMode::from_env("UA_FILTER_MODE"), Vec::new());
+        filter.rule_file = env::var("UA_FILTER_FILE")
+            .ok()
+            .filter(|path| !path.is_empty());
+        filter.reload_interval = Duration::from_secs(
+            env::var("UA_FILTER_RELOAD_INTERVAL")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(DEFAULT_RELOAD_INTERVAL),
+        );
+        if let Some(status) = env::var("UA_FILTER_REJECT_STATUS")
+            .ok()
+            .and_then(|v| v.parse::<u16>().ok())
+            .and_then(|v| StatusCode::from_u16(v).ok())
+        {
+            filter.reject_status = status;
+        }
+        if let Ok(message) = env::var("UA_FILTER_REJECT_MESSAGE")
+            && !message.is_empty()
+        {
+            filter.reject_message = message;
+        }
+        filter
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.mode != UserAgentFilterMode::Off
+    }
+
+    pub fn set_rules(&self, rules: Vec<UserAgentRule>) {
+        let rules = rules.into_iter().map(CompiledRule::new).collect();
+        *self.rules.write().unwrap() = Arc::new(rules);
+    }
+
+    pub fn check(&self, user_agent: &str) -> UserAgentDecision {
+        let rules = self.rules.read().unwrap().clone();
+
+        if let Some(rule) = rules
+            .iter()
+            .find(|rule| rule.action == UserAgentAction::Deny && rule.matches(user_agent))
+        {
+            return UserAgentDecision::Denied {
+                pattern: rule.pattern.clone(),
+            };
+        }
+
+        let mut allow_rules = rules
+            .iter()
+            .filter(|rule| rule.action == UserAgentAction::Allow)
+            .peekable();
+        if allow_rules.peek().is_none() {
+            return UserAgentDecision::Allowed { pattern: None };
+        }
+        match allow_rules.find(|rule| rule.matches(user_agent)) {
+            Some(rule) => UserAgentDecision::Allowed {
+                pattern: Some(rule.pattern.clone()),
+            },
+            None => UserAgentDecision::Unlisted,
+        }
+    }
+
+    async fn load_rules(
+        &self,
+        db: &mongodb::Client,
+    ) -> Result<Vec<UserAgentRule>, Box<dyn std::error::Error + Send + Sync>> {
+        match &self.rule_file {
+            Some(path) => {
+                let content = std::fs::read_to_string(path)?;
+                Ok(serde_json::from_str(&content)?)
+            }
+            None => Ok(db
+                .database(get_database_name().as_str())
+                .collection::<UserAgentRule>(USER_AGENT_RULE_COLLECTION)
+                .find(bson::doc! {})
+                .await?
+                .try_collect()
+                .await?),
+        }
+    }
+
+    pub async fn reload(&self, db: &mongodb::Client) {
+        match self.load_rules(db).await {
+            Ok(rules) => {
+                let count = rules.len();
+                self.set_rules(rules);
+                log::debug!("Loaded {} User-Agent rules", count);
+            }
+            Err(e) => log::warn!("Failed to reload User-Agent rules: {}", e),
+        }
+    }
+
+    pub fn spawn_reload(&self, db: mongodb::Client) {
+        let filter = self.clone();
+        actix_web::rt::spawn(async move {
+            loop {
+                actix_web::rt::time::sleep(filter.reload_interval).await;
+                filter.reload(&db).await;
+            }
+        });
+    }
+
+    fn reject_response(&self) -> HttpResponse {
+        HttpResponse::build(self.reject_status).json(ErrorResponse {
+            code: self.reject_status.as_u16(),
+            error: self
+                .reject_status
+                .canonical_reason()
+                .unwrap_or("Forbidden")
+                .to_string(),
+            detail: Some(self.reject_message.clone()),
+        })
+    }
+}
+
+/// 按 `UA_FILTER_MODE` 过滤请求的 User-Agent
+pub async fn filter_user_agent(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let filter = match req.app_data::<web::Data<AppState>>() {
+        Some(state) if state.user_agent_filter.is_enabled() => state.user_agent_filter.clone(),
+        _ => return Ok(next.call(req).await?.map_into_left_body()),
+    };
+    if EXEMPT_PATH_PREFIXES
+        .iter()
+        .any(|prefix| req.path().starts_with(prefix))
+    {
+        return Ok(next.call(req).await?.map_into_left_body());
+    }
+
+    let user_agent = req
+        .headers()
+        .get(header::USER_AGENT)
+        .and_then(|hv| hv.to_str().ok())
+        .unwrap_or("")
+        .to_string();
+
+    let reason = match filter.check(&user_agent) {
+        UserAgentDecision::Allowed { pattern } => {
+            if let Some(pattern) = pattern {
+                USER_AGENT_RULE_HITS
+                    .with_label_values(&[pattern.as_str(), "allow"])
+                    .inc();
+            }
+            return Ok(next.call(req).await?.map_into_left_body());
+        }
+        UserAgentDecision::Denied { pattern } => {
+            USER_AGENT_RULE_HITS
+                .with_label_values(&[pattern.as_str(), "deny"])
+                .inc();
+            "deny"
+        }
+        UserAgentDecision::Unlisted => "unlisted",
+    };
+
+    USER_AGENT_REJECTED
+        .with_label_values(&[filter.mode.as_str(), reason])
+        .inc();
+
+    if filter.mode == UserAgentFilterMode::Monitor {
+        log::debug!("User-Agent {:?} would be rejected ({})", user_agent, reason);
+        return Ok(next.call(req).await?.map_into_left_body());
+    }
+
+    log::debug!("Rejected User-Agent {:?} ({})", user_agent, reason);
+    Ok(req.into_response(filter.reject_response().map_into_right_body()))
+}
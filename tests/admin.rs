@@ -0,0 +1,192 @@
+use actix_web::{
+    body::to_bytes,
+    dev::Service,
+    http::StatusCode,
+    test::{TestRequest, init_service},
+};
+use serde_json::json;
+
+use mcim_rust_api::db::indexes::create_indexes;
+use mcim_rust_api::errors::ServiceError;
+use mcim_rust_api::models::admin::entities::AdminRole;
+use mcim_rust_api::services::admin_token::AdminTokenService;
+use mcim_rust_api::test_utils::{create_test_app, init_test_db};
+
+// 每个测试创建独立的 token，结束后吊销
+async fn create_token(role: AdminRole) -> (String, String) {
+    let service = AdminTokenService::new(init_test_db().await);
+    let name = format!("test-{}", rand::random::<u32>());
+    let (token, _) = service.create_token(&name, role).await.unwrap();
+    (name, token)
+}
+
+async fn revoke_token(name: &str) {
+    let service = AdminTokenService::new(init_test_db().await);
+    service.revoke_token(name).await.unwrap();
+}
+
+fn bearer(token: &str) -> (&'static str, String) {
+    ("Authorization", format!("Bearer {}", token))
+}
+
+#[actix_web::test]
+async fn test_admin_requires_token() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get().uri("/admin/queues").to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let req = TestRequest::get()
+        .uri("/admin/queues")
+        .insert_header(bearer("wrong-token"))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[actix_web::test]
+async fn test_create_duplicate_token() {
+    let db = init_test_db().await;
+    create_indexes(&db).await.unwrap();
+    let service = AdminTokenService::new(db);
+    let name = format!("test-{}", rand::random::<u32>());
+
+    // 并发创建时只有一个成功
+    let (first, second) = futures::join!(
+        service.create_token(&name, AdminRole::Operator),
+        service.create_token(&name, AdminRole::Admin)
+    );
+    assert_eq!(first.is_ok() as u8 + second.is_ok() as u8, 1);
+    let err = first.err().or(second.err()).unwrap();
+    assert!(matches!(err, ServiceError::InvalidInput { ref field, .. } if field == "name"));
+
+    service.revoke_token(&name).await.unwrap();
+}
+
+#[actix_web::test]
+async fn test_revoked_token_rejected() {
+    let app = init_service(create_test_app().await).await;
+    let (name, token) = create_token(AdminRole::Admin).await;
+    revoke_token(&name).await;
+
+    let req = TestRequest::get()
+        .uri("/admin/queues")
+        .insert_header(bearer(&token))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[actix_web::test]
+async fn test_operator_is_read_only() {
+    let app = init_service(create_test_app().await).await;
+    let (name, token) = create_token(AdminRole::Operator).await;
+
+    let req = TestRequest::get()
+        .uri("/admin/queues")
+        .insert_header(bearer(&token))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert!(resp.status().is_success());
+
+    let req = TestRequest::delete()
+        .uri("/admin/queues/modrinth_project_ids")
+        .insert_header(bearer(&token))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    revoke_token(&name).await;
+}
+
+#[actix_web::test]
+async fn test_list_queues() {
+    let app = init_service(create_test_app().await).await;
+    let (name, token) = create_token(AdminRole::Admin).await;
+
+    let req = TestRequest::get()
+        .uri("/admin/queues")
+        .insert_header(bearer(&token))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    let queues = json_body.as_array().unwrap();
+    assert!(queues.iter().any(|q| q["queue"] == "curseforge_modids"));
+
+    revoke_token(&name).await;
+}
+
+#[actix_web::test]
+async fn test_requeue_and_remove_members() {
+    let app = init_service(create_test_app().await).await;
+    let (name, token) = create_token(AdminRole::Admin).await;
+    // 需符合 Modrinth ID 格式
+    let member = format!("T{:07}", rand::random::<u32>() % 10_000_000);
+
+    let req = TestRequest::post()
+        .uri("/admin/queues/modrinth_project_ids/requeue")
+        .insert_header(bearer(&token))
+        .set_json(json!({ "members": [member] }))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert!(resp.status().is_success());
+
+    let req = TestRequest::post()
+        .uri("/admin/queues/modrinth_project_ids/remove")
+        .insert_header(bearer(&token))
+        .set_json(json!({ "members": [member] }))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert!(resp.status().is_success());
+
+    let json_body: serde_json::Value =
+        serde_json::from_slice(&to_bytes(resp.into_body()).await.unwrap()).unwrap();
+    assert_eq!(json_body["affected"], 1);
+
+    revoke_token(&name).await;
+}
+
+#[actix_web::test]
+async fn test_requeue_invalid_members() {
+    let app = init_service(create_test_app().await).await;
+    let (name, token) = create_token(AdminRole::Admin).await;
+
+    for (queue, member) in [
+        ("curseforge_modids", "sodium"),
+        ("curseforge_fileids", "-5"),
+        ("modrinth_project_ids", "not-a-project-id"),
+        ("modrinth_hashes_sha1", "f046f176"),
+    ] {
+        let req = TestRequest::post()
+            .uri(&format!("/admin/queues/{}/requeue", queue))
+            .insert_header(bearer(&token))
+            .set_json(json!({ "members": [member] }))
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "Queue: {}", queue);
+    }
+
+    revoke_token(&name).await;
+}
+
+#[actix_web::test]
+async fn test_unknown_queue() {
+    let app = init_service(create_test_app().await).await;
+    let (name, token) = create_token(AdminRole::Admin).await;
+
+    let req = TestRequest::get()
+        .uri("/admin/queues/unknown_queue")
+        .insert_header(bearer(&token))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    revoke_token(&name).await;
+}
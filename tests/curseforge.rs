@@ -5,7 +5,8 @@ use actix_web::{
 };
 use serde_json::json;
 
-use mcim_rust_api::test_utils::create_test_app;
+use mcim_rust_api::test_utils::{create_test_app, create_test_app_with};
+use mcim_rust_api::utils::app::SearchMode;
 
 static MOD_ID: i32 = 594678;
 static FILE_ID: i32 = 3913840;
@@ -13,6 +14,9 @@ static FILE_ID: i32 = 3913840;
 static CLASS_ID: i32 = 6;
 const GAME_ID: i32 = 432;
 const MOD_IDS: [i32; 2] = [946010, 594678];
+const NON_JAVA_MOD_ID: i32 = 1120001;
+// 只有按年份编号的 Minecraft 26.1 版本类型
+const YEAR_VERSIONED_MOD_ID: i32 = 1120002;
 const FILE_IDS: [i32; 2] = [3913840, 5976953];
 const FINGERPRINTS: [i32; 2] = [2070800629, 1904165976];
 
@@ -91,6 +95,44 @@ async fn test_get_mods_success() {
     assert!(status.is_success(), "Status: {}, Body: {}", status, body);
 }
 
+#[actix_web::test]
+async fn test_get_mods_filter_pc_only() {
+    let app = init_service(create_test_app().await).await;
+
+    // NON_JAVA_MOD_ID 的文件只有 Modloader 与 Environment 版本类型，应被过滤；
+    // YEAR_VERSIONED_MOD_ID 只有 Minecraft 26.1，应保留
+    let payload = json!({
+        "modIds": [MOD_IDS[0], MOD_IDS[1], NON_JAVA_MOD_ID, YEAR_VERSIONED_MOD_ID],
+        "filterPcOnly": true
+    });
+
+    let req = TestRequest::post()
+        .uri("/curseforge/v1/mods")
+        .set_json(payload)
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    let ids: Vec<i64> = json_body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|m| m["id"].as_i64())
+        .collect();
+    assert_eq!(ids.len(), MOD_IDS.len() + 1, "Body: {}", body);
+    assert!(!ids.contains(&(NON_JAVA_MOD_ID as i64)), "Body: {}", body);
+    assert!(
+        ids.contains(&(YEAR_VERSIONED_MOD_ID as i64)),
+        "Body: {}",
+        body
+    );
+}
+
 #[actix_web::test]
 async fn test_get_mods_not_found() {
     let app = init_service(create_test_app().await).await;
@@ -351,3 +393,396 @@ async fn test_get_categories_with_class_only_filters() {
     let body = String::from_utf8_lossy(&body_bytes);
     assert!(status.is_success(), "Status: {}, Body: {}", status, body);
 }
+
+#[actix_web::test]
+async fn test_get_games_success() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get().uri("/curseforge/v1/games").to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_get_game_success() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri(&format!("/curseforge/v1/games/{}", GAME_ID))
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_get_game_not_mirrored() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri("/curseforge/v1/games/1")
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), 404);
+}
+
+#[actix_web::test]
+async fn test_get_game_versions_success() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri(&format!("/curseforge/v1/games/{}/versions", GAME_ID))
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_get_game_versions_v2_success() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri(&format!("/curseforge/v2/games/{}/versions", GAME_ID))
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_get_game_version_types_success() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri(&format!("/curseforge/v1/games/{}/version-types", GAME_ID))
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_get_mod_description_success() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri(&format!("/curseforge/v1/mods/{}/description", MOD_ID))
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_get_mod_description_not_found() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri("/curseforge/v1/mods/999999/description")
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), 404);
+}
+
+#[actix_web::test]
+async fn test_get_file_changelog_success() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri(&format!(
+            "/curseforge/v1/mods/{}/files/{}/changelog",
+            MOD_ID, FILE_ID
+        ))
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_get_minecraft_versions_success() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri("/curseforge/v1/minecraft/version?sortDescending=true")
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_get_minecraft_version_success() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri("/curseforge/v1/minecraft/version/1.20.1")
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_get_minecraft_modloaders_success() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri("/curseforge/v1/minecraft/modloader?version=1.20.1&includeAll=true")
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_get_minecraft_modloader_success() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri("/curseforge/v1/minecraft/modloader/forge-47.2.0")
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_get_minecraft_modloader_not_found() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri("/curseforge/v1/minecraft/modloader/forge-0.0.0")
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), 404);
+}
+
+#[actix_web::test]
+async fn test_get_fingerprints_partial_match() {
+    let app = init_service(create_test_app().await).await;
+    // assets 文件夹的 module fingerprint
+    let module_fingerprints: Vec<i64> = vec![3455112628];
+    let payload = json!({
+        "fingerprints": module_fingerprints
+    });
+
+    let req = TestRequest::post()
+        .uri("/curseforge/v1/fingerprints")
+        .set_json(json!(payload))
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    let partial_matches = json_body["data"]["partialMatches"].as_array().unwrap();
+    assert!(
+        partial_matches
+            .iter()
+            .any(|m| m["file"]["id"].as_i64() == Some(FILE_ID as i64)),
+        "Body: {}",
+        body
+    );
+}
+
+#[actix_web::test]
+async fn test_get_fuzzy_fingerprints_success() {
+    let app = init_service(create_test_app().await).await;
+
+    let payload = json!({
+        "gameId": GAME_ID,
+        "fingerprints": [
+            {
+                "foldername": "hats-and-cosmetics",
+                "fingerprints": [3455112628i64, 2592535084i64]
+            }
+        ]
+    });
+
+    let req = TestRequest::post()
+        .uri("/curseforge/v1/fingerprints/fuzzy")
+        .set_json(json!(payload))
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    let fuzzy_matches = json_body["data"]["fuzzyMatches"].as_array().unwrap();
+    assert_eq!(
+        fuzzy_matches[0]["file"]["id"].as_i64(),
+        Some(FILE_ID as i64),
+        "Body: {}",
+        body
+    );
+}
+
+#[actix_web::test]
+async fn test_get_mod_files_with_extended_filters() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri(&format!(
+            "/curseforge/v1/mods/{}/files?modLoaderTypes=1,6&releaseType=1&sortField=fileName&sortOrder=asc",
+            MOD_ID
+        ))
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_get_mod_files_invalid_loader_type() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::get()
+        .uri(&format!(
+            "/curseforge/v1/mods/{}/files?modLoaderType=99",
+            MOD_ID
+        ))
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_search_local() {
+    let app = init_service(
+        create_test_app_with(|state| state.curseforge_search_mode = SearchMode::Local).await,
+    )
+    .await;
+
+    let req = TestRequest::get()
+        .uri("/curseforge/v1/mods/search?gameId=432&classId=6&searchFilter=christmas&modLoaderTypes=[NeoForge]&sortField=6&sortOrder=desc&index=0&pageSize=50")
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    assert!(
+        json_body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|m| m["id"].as_i64() == Some(MOD_IDS[0] as i64)),
+        "Body: {}",
+        body
+    );
+    assert!(json_body["pagination"]["totalCount"].as_i64().unwrap() >= 1);
+}
+
+#[actix_web::test]
+async fn test_search_local_sort_by_popularity() {
+    let app = init_service(
+        create_test_app_with(|state| state.curseforge_search_mode = SearchMode::Local).await,
+    )
+    .await;
+
+    let req = TestRequest::get()
+        .uri("/curseforge/v1/mods/search?sortField=2&sortOrder=desc&pageSize=50")
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    // gamePopularityRank 越小越热门，desc 时应升序返回
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    let ranks: Vec<i64> = json_body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|m| m["gamePopularityRank"].as_i64())
+        .collect();
+    assert!(ranks.len() >= 2, "Body: {}", body);
+    assert!(ranks.windows(2).all(|w| w[0] <= w[1]), "Body: {}", body);
+}
+
+#[actix_web::test]
+async fn test_search_local_invalid_sort_field() {
+    let app = init_service(
+        create_test_app_with(|state| state.curseforge_search_mode = SearchMode::Local).await,
+    )
+    .await;
+
+    let req = TestRequest::get()
+        .uri("/curseforge/v1/mods/search?sortField=999")
+        .to_request();
+
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_search_local_invalid_mod_loader_type() {
+    let app = init_service(
+        create_test_app_with(|state| state.curseforge_search_mode = SearchMode::Local).await,
+    )
+    .await;
+
+    for uri in [
+        "/curseforge/v1/mods/search?modLoaderType=99",
+        "/curseforge/v1/mods/search?modLoaderType=-5",
+        "/curseforge/v1/mods/search?modLoaderTypes=1,99",
+    ] {
+        let req = TestRequest::get().uri(uri).to_request();
+        let resp = app.call(req).await.unwrap();
+        assert_eq!(resp.status(), 400, "URI: {}", uri);
+    }
+}
@@ -0,0 +1,102 @@
+use actix_web::{
+    body::to_bytes,
+    dev::Service,
+    http::StatusCode,
+    test::{TestRequest, init_service},
+};
+use serde_json::json;
+
+use mcim_rust_api::test_utils::create_test_app;
+
+#[actix_web::test]
+async fn test_resolve_modrinth_dependencies() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::post()
+        .uri("/dependencies/modrinth")
+        .set_json(json!({
+            "version_ids": ["U9tSAZs1", "U9UF2NCO", "notexist"],
+            "loader": "fabric",
+            "game_version": "1.21"
+        }))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    let install = json_body["install"].as_array().unwrap();
+    assert_eq!(install.len(), 1, "Body: {}", body);
+    assert_eq!(install[0]["project_id"], "Wnxd13zP");
+    assert_eq!(install[0]["depth"], 0);
+
+    // 同一项目的两个版本
+    let conflicts = json_body["conflicts"].as_array().unwrap();
+    assert_eq!(conflicts.len(), 1, "Body: {}", body);
+    assert_eq!(conflicts[0]["project_id"], "Wnxd13zP");
+
+    // Fabric API 不在测试数据中
+    let unresolved = json_body["unresolved"].as_array().unwrap();
+    assert!(
+        unresolved
+            .iter()
+            .any(|u| u["project_id"] == "P7dR8mSH" && u["required_by"] == "U9tSAZs1"),
+        "Body: {}",
+        body
+    );
+    assert!(unresolved.iter().any(|u| u["version_id"] == "notexist"));
+}
+
+#[actix_web::test]
+async fn test_resolve_curseforge_dependencies() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::post()
+        .uri("/dependencies/curseforge")
+        .set_json(json!({
+            "fileIds": [3913840],
+            "modLoaderType": 1,
+            "gameVersion": "1.19.1"
+        }))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    let install = json_body["install"].as_array().unwrap();
+    assert_eq!(install[0]["project_id"], "594678");
+    assert_eq!(install[0]["version_id"], "3913840");
+    assert!(
+        json_body["unresolved"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|u| u["project_id"] == "309927"),
+        "Body: {}",
+        body
+    );
+}
+
+#[actix_web::test]
+async fn test_resolve_dependencies_invalid() {
+    let app = init_service(create_test_app().await).await;
+
+    let req = TestRequest::post()
+        .uri("/dependencies/modrinth")
+        .set_json(json!({ "version_ids": [] }))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = TestRequest::post()
+        .uri("/dependencies/curseforge")
+        .set_json(json!({ "fileIds": [3913840], "modLoaderType": 99 }))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,92 @@
+use actix_web::{
+    body::to_bytes,
+    dev::Service,
+    http::StatusCode,
+    test::{TestRequest, init_service},
+};
+
+use mcim_rust_api::test_utils::create_test_app;
+
+const BOUNDARY: &str = "mcim-test-boundary";
+
+fn multipart_body(files: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut body = Vec::new();
+    for (filename, data) in files {
+        body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\nContent-Type: application/java-archive\r\n\r\n",
+                BOUNDARY, filename
+            )
+            .as_bytes(),
+        );
+        body.extend_from_slice(data);
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{}--\r\n", BOUNDARY).as_bytes());
+    body
+}
+
+#[actix_web::test]
+async fn test_identify_files() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::post()
+        .uri("/identify")
+        .insert_header((
+            "Content-Type",
+            format!("multipart/form-data; boundary={}", BOUNDARY),
+        ))
+        .set_payload(multipart_body(&[("test.jar", b"hello world\n")]))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    let files = json_body["files"].as_array().unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0]["filename"], "test.jar");
+    assert_eq!(files[0]["size"], 12);
+    assert_eq!(files[0]["fingerprint"], 2824650221u32);
+    assert_eq!(files[0]["sha1"], "22596363b3de40b06f981fb85d82312e8c0ed511");
+}
+
+#[actix_web::test]
+async fn test_identify_empty_upload() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::post()
+        .uri("/identify")
+        .insert_header((
+            "Content-Type",
+            format!("multipart/form-data; boundary={}", BOUNDARY),
+        ))
+        .set_payload(multipart_body(&[]))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_identify_total_size_limit() {
+    let app = init_service(create_test_app().await).await;
+    // 单个文件未超限，但合计超过 64 MiB
+    let data = vec![0u8; 30 * 1024 * 1024];
+    let req = TestRequest::post()
+        .uri("/identify")
+        .insert_header((
+            "Content-Type",
+            format!("multipart/form-data; boundary={}", BOUNDARY),
+        ))
+        .set_payload(multipart_body(&[
+            ("a.jar", &data),
+            ("b.jar", &data),
+            ("c.jar", &data),
+        ]))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
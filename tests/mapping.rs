@@ -0,0 +1,88 @@
+use actix_web::{
+    body::to_bytes,
+    dev::Service,
+    http::StatusCode,
+    test::{TestRequest, init_service},
+};
+use serde_json::json;
+
+use mcim_rust_api::test_utils::create_test_app;
+
+#[actix_web::test]
+async fn test_modrinth_mapping() {
+    let app = init_service(create_test_app().await).await;
+
+    // slug 会被解析为 project_id
+    for uri in ["/mapping/modrinth/Wnxd13zP", "/mapping/modrinth/clumps"] {
+        let req = TestRequest::get().uri(uri).to_request();
+        let resp = app.call(req).await.unwrap();
+
+        let status = resp.status();
+        let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+        let body = String::from_utf8_lossy(&body_bytes);
+        assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+        let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(json_body["project_id"], "Wnxd13zP");
+        assert!(json_body["total_files"].as_i64().unwrap() > 0);
+        // mapping-fixture.jar 与 Curseforge 文件 3913840 的 sha1 相同
+        assert_eq!(
+            json_body["curseforge"],
+            json!([{
+                "mod_id": 594678,
+                "slug": "project-594678",
+                "name": "project-594678",
+                "matched_files": 1
+            }]),
+            "Body: {}",
+            body
+        );
+    }
+}
+
+#[actix_web::test]
+async fn test_modrinth_mapping_not_found() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::get()
+        .uri("/mapping/modrinth/not-a-project")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn test_curseforge_mapping() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::get()
+        .uri("/mapping/curseforge/594678")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    assert_eq!(json_body["mod_id"], 594678);
+    assert!(json_body["total_files"].as_i64().unwrap() > 0);
+    assert_eq!(
+        json_body["modrinth"],
+        json!([{
+            "project_id": "Wnxd13zP",
+            "slug": "clumps",
+            "title": "Clumps",
+            "matched_files": 1
+        }]),
+        "Body: {}",
+        body
+    );
+}
+
+#[actix_web::test]
+async fn test_curseforge_mapping_not_found() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::get().uri("/mapping/curseforge/1").to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
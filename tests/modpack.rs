@@ -0,0 +1,133 @@
+use actix_web::{
+    body::to_bytes,
+    dev::Service,
+    http::StatusCode,
+    test::{TestRequest, init_service},
+};
+use serde_json::json;
+
+use mcim_rust_api::test_utils::create_test_app;
+
+#[actix_web::test]
+async fn test_resolve_mrpack_index() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::post()
+        .uri("/modpack/resolve")
+        .set_json(json!({
+            "formatVersion": 1,
+            "game": "minecraft",
+            "versionId": "1.0.0",
+            "name": "Test Pack",
+            "files": [
+                {
+                    "path": "mods/Clumps-forge-1.18.1-8.0.2.jar",
+                    "hashes": { "sha1": "f0cea90219f681c3183e0d37d021cb8902d2d085" },
+                    "downloads": ["https://cdn.modrinth.com/data/Wnxd13zP/versions/3AH29I4c/Clumps-forge-1.18.1-8.0.2.jar"],
+                    "fileSize": 10215
+                },
+                {
+                    "path": "mods/unknown.jar",
+                    "hashes": { "sha1": "0000000000000000000000000000000000000000" },
+                    "env": { "client": "optional", "server": "unsupported" },
+                    "downloads": ["https://cdn.modrinth.com/data/AAAAAAAA/versions/BBBBBBBB/unknown.jar"],
+                    "fileSize": 1
+                }
+            ],
+            "dependencies": { "minecraft": "1.18.1", "forge": "39.0.0" }
+        }))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    assert_eq!(json_body["platform"], "modrinth");
+    assert_eq!(json_body["game_version"], "1.18.1");
+
+    let files = json_body["files"].as_array().unwrap();
+    assert_eq!(files.len(), 1, "Body: {}", body);
+    assert_eq!(files[0]["project_id"], "Wnxd13zP");
+    assert_eq!(files[0]["size"], 10215);
+    assert!(
+        files[0]["url"]
+            .as_str()
+            .unwrap()
+            .ends_with("/data/Wnxd13zP/versions/3AH29I4c/Clumps-forge-1.18.1-8.0.2.jar")
+    );
+
+    let unresolved = json_body["unresolved"].as_array().unwrap();
+    assert_eq!(unresolved.len(), 1, "Body: {}", body);
+    assert_eq!(unresolved[0]["path"], "mods/unknown.jar");
+    assert_eq!(unresolved[0]["downloads"].as_array().unwrap().len(), 1);
+}
+
+#[actix_web::test]
+async fn test_resolve_curseforge_manifest() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::post()
+        .uri("/modpack/resolve")
+        .set_json(json!({
+            "minecraft": {
+                "version": "1.19.1",
+                "modLoaders": [{ "id": "forge-42.0.0", "primary": true }]
+            },
+            "manifestType": "minecraftModpack",
+            "manifestVersion": 1,
+            "name": "Test Pack",
+            "version": "1.0.0",
+            "author": "mcim",
+            "files": [
+                { "projectID": 594678, "fileID": 3913840, "required": true },
+                { "projectID": 1, "fileID": 1, "required": false }
+            ],
+            "overrides": "overrides"
+        }))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    assert_eq!(json_body["platform"], "curseforge");
+
+    let files = json_body["files"].as_array().unwrap();
+    assert_eq!(files.len(), 1, "Body: {}", body);
+    assert_eq!(files[0]["version_id"], "3913840");
+    assert_eq!(
+        files[0]["hashes"]["sha1"],
+        "f046f176352dbcfac8fe61bdfa3f8ea5d32f778f"
+    );
+    assert!(
+        files[0]["url"]
+            .as_str()
+            .unwrap()
+            .ends_with("/files/3913/840/hats-and-cosmetics-1.2.2-1.19.1.jar")
+    );
+
+    let unresolved = json_body["unresolved"].as_array().unwrap();
+    assert_eq!(unresolved.len(), 1, "Body: {}", body);
+    assert_eq!(unresolved[0]["version_id"], "1");
+}
+
+#[actix_web::test]
+async fn test_resolve_modpack_invalid() {
+    let app = init_service(create_test_app().await).await;
+
+    for body in [
+        json!({ "files": [] }),
+        json!({ "files": [{ "path": "a" }] }),
+    ] {
+        let req = TestRequest::post()
+            .uri("/modpack/resolve")
+            .set_json(body)
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
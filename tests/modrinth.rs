@@ -6,11 +6,15 @@ use actix_web::{
 };
 use serde_json::json;
 
-use mcim_rust_api::test_utils::create_test_app;
+use mcim_rust_api::services::modrinth::is_modrinth_id;
+use mcim_rust_api::test_utils::{create_test_app, create_test_app_with};
+use mcim_rust_api::utils::app::SearchMode;
 
 // 测试数据常量
 const PROJECT_IDS: &[&str] = &["Wnxd13zP", "Ua7DFN59"];
 const SLUGS: &[&str] = &["yungs-api", "clumps"];
+const TEAM_IDS: &[&str] = &["3TidTIHz", "twbRaNO5"];
+const USER_IDS: &[&str] = &["HCGwycZf", "l45nT5ov"];
 const VERSION_IDS: &[&str] = &[
     "dpSzBMP6", "IOIGqCVr", "xVBjqLw6", "8jfhokYb", "fTWVa6NX", "Km2A7nLe",
 ];
@@ -76,6 +80,50 @@ async fn test_modrinth_search() {
     assert!(status.is_success(), "Status: {}, Body: {}", status, body);
 }
 
+#[actix_web::test]
+async fn test_modrinth_search_local() {
+    let app = init_service(
+        create_test_app_with(|state| state.modrinth_search_mode = SearchMode::Local).await,
+    )
+    .await;
+
+    // facets=[["categories:library"],["project_type:mod"],["license:lgpl-3.0-only"]]
+    let req = TestRequest::get()
+        .uri("/modrinth/v2/search?query=yung&facets=%5B%5B%22categories%3Alibrary%22%5D%2C%5B%22project_type%3Amod%22%5D%2C%5B%22license%3Algpl-3.0-only%22%5D%5D&index=downloads&limit=10")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    let hits = json_body["hits"].as_array().unwrap();
+    assert!(
+        hits.iter().any(|hit| hit["project_id"] == PROJECT_IDS[1]),
+        "Body: {}",
+        body
+    );
+    assert!(hits.iter().all(|hit| hit["project_id"] != PROJECT_IDS[0]));
+    assert_eq!(json_body["limit"], 10);
+}
+
+#[actix_web::test]
+async fn test_modrinth_search_local_invalid_facet() {
+    let app = init_service(
+        create_test_app_with(|state| state.modrinth_search_mode = SearchMode::Local).await,
+    )
+    .await;
+
+    // facets=[["unknown:value"]]
+    let req = TestRequest::get()
+        .uri("/modrinth/v2/search?facets=%5B%5B%22unknown%3Avalue%22%5D%5D")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
 #[actix_web::test]
 async fn test_modrinth_project_by_project_id() {
     let app = init_service(create_test_app().await).await;
@@ -242,6 +290,84 @@ async fn test_modrinth_project_versions_by_slug() {
     }
 }
 
+#[actix_web::test]
+async fn test_modrinth_project_version_by_number() {
+    let app = init_service(create_test_app().await).await;
+
+    for (idslug, version) in [
+        ("Ua7DFN59", "1.21-Fabric-5.0.0"),
+        ("yungs-api", "1.21-Fabric-5.0.0"),
+        ("yungs-api", "Nx7XHO30"),
+    ] {
+        let req = TestRequest::get()
+            .uri(&format!(
+                "/modrinth/v2/project/{}/version/{}",
+                idslug,
+                urlencoding::encode(version)
+            ))
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+
+        let status = resp.status();
+        let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+        let body = String::from_utf8_lossy(&body_bytes);
+        assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+        let json_body: serde_json::Value =
+            serde_json::from_str(&body).expect("Failed to parse JSON response");
+        assert_eq!(json_body["id"].as_str(), Some("Nx7XHO30"));
+    }
+}
+
+#[actix_web::test]
+async fn test_modrinth_project_version_not_found() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::get()
+        .uri("/modrinth/v2/project/Ua7DFN59/version/0.0.0-not-exist")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn test_modrinth_project_dependencies() {
+    let app = init_service(create_test_app().await).await;
+
+    // yungs-api 的 dEpFiXt1 依赖了未入库的 Version，其 Project 仍应返回；
+    // 其余依赖的 Project 与 Version 均未入库
+    let expected: &[(&str, &[&str])] = &[
+        (PROJECT_IDS[0], &[]),
+        (PROJECT_IDS[1], &[PROJECT_IDS[0]]),
+        (SLUGS[0], &[PROJECT_IDS[0]]),
+        (SLUGS[1], &[]),
+    ];
+
+    for (idslug, expected_project_ids) in expected {
+        let req = TestRequest::get()
+            .uri(&format!("/modrinth/v2/project/{}/dependencies", idslug))
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+
+        let status = resp.status();
+        let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+        let body = String::from_utf8_lossy(&body_bytes);
+        assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+        let json_body: serde_json::Value =
+            serde_json::from_str(&body).expect("Failed to parse JSON response");
+
+        let project_ids: Vec<&str> = json_body["projects"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|p| p["id"].as_str())
+            .collect();
+        assert_eq!(&project_ids, expected_project_ids, "Body: {}", body);
+        assert_eq!(json_body["versions"], json!([]), "Body: {}", body);
+    }
+}
+
 #[actix_web::test]
 async fn test_modrinth_version() {
     let app = init_service(create_test_app().await).await;
@@ -589,6 +715,152 @@ async fn test_modrinth_version_files_update_empty() {
     }
 }
 
+#[actix_web::test]
+async fn test_modrinth_project_members() {
+    let app = init_service(create_test_app().await).await;
+
+    for idslug in PROJECT_IDS.iter().chain(SLUGS.iter()) {
+        let req = TestRequest::get()
+            .uri(&format!("/modrinth/v2/project/{}/members", idslug))
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+
+        let status = resp.status();
+        let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+        let body = String::from_utf8_lossy(&body_bytes);
+        assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+        let json_body = serde_json::from_str::<Vec<serde_json::Value>>(&body)
+            .expect("Failed to parse JSON response");
+        assert!(!json_body.is_empty(), "Expected members for {}", idslug);
+    }
+}
+
+#[actix_web::test]
+async fn test_modrinth_team_members() {
+    let app = init_service(create_test_app().await).await;
+
+    for team_id in TEAM_IDS {
+        let req = TestRequest::get()
+            .uri(&format!("/modrinth/v2/team/{}/members", team_id))
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+
+        let status = resp.status();
+        let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+        let body = String::from_utf8_lossy(&body_bytes);
+        assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+        let json_body = serde_json::from_str::<Vec<serde_json::Value>>(&body)
+            .expect("Failed to parse JSON response");
+        for member in json_body {
+            assert_eq!(member["team_id"].as_str(), Some(*team_id));
+        }
+    }
+}
+
+#[actix_web::test]
+async fn test_modrinth_teams() {
+    let app = init_service(create_test_app().await).await;
+    let ids_json = serde_json::to_string(TEAM_IDS).unwrap();
+    let req = TestRequest::get()
+        .uri(&format!(
+            "/modrinth/v2/teams?ids={}",
+            urlencoding::encode(&ids_json)
+        ))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body = serde_json::from_str::<Vec<Vec<serde_json::Value>>>(&body)
+        .expect("Failed to parse JSON response");
+    assert_eq!(json_body.len(), TEAM_IDS.len());
+}
+
+#[actix_web::test]
+async fn test_modrinth_teams_not_found() {
+    let app = init_service(create_test_app().await).await;
+    let ids_json = serde_json::to_string(&["notfound"]).unwrap();
+    let req = TestRequest::get()
+        .uri(&format!(
+            "/modrinth/v2/teams?ids={}",
+            urlencoding::encode(&ids_json)
+        ))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+    assert_eq!(body, "[]");
+}
+
+#[test]
+fn test_is_modrinth_id() {
+    assert!(is_modrinth_id("AANobbMI"));
+    assert!(!is_modrinth_id("jellysquid3"));
+    assert!(!is_modrinth_id("sodium"));
+    assert!(!is_modrinth_id("AANob-MI"));
+}
+
+#[actix_web::test]
+async fn test_modrinth_team_not_found() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::get()
+        .uri("/modrinth/v2/team/notfound/members")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn test_modrinth_user() {
+    let app = init_service(create_test_app().await).await;
+
+    for user_id in USER_IDS {
+        let req = TestRequest::get()
+            .uri(&format!("/modrinth/v2/user/{}", user_id))
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+
+        let status = resp.status();
+        let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+        let body = String::from_utf8_lossy(&body_bytes);
+        assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+        let json_body: serde_json::Value =
+            serde_json::from_str(&body).expect("Failed to parse JSON response");
+        assert_eq!(json_body["id"].as_str(), Some(*user_id));
+    }
+}
+
+#[actix_web::test]
+async fn test_modrinth_user_projects() {
+    let app = init_service(create_test_app().await).await;
+
+    for user_id in USER_IDS {
+        let req = TestRequest::get()
+            .uri(&format!("/modrinth/v2/user/{}/projects", user_id))
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+
+        let status = resp.status();
+        let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+        let body = String::from_utf8_lossy(&body_bytes);
+        assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+        let json_body = serde_json::from_str::<Vec<serde_json::Value>>(&body)
+            .expect("Failed to parse JSON response");
+        assert!(!json_body.is_empty(), "Expected projects for {}", user_id);
+    }
+}
+
 #[actix_web::test]
 async fn test_modrinth_tag_category() {
     let app = init_service(create_test_app().await).await;
@@ -630,3 +902,111 @@ async fn test_modrinth_tag_game_version() {
     let body = String::from_utf8_lossy(&body_bytes);
     assert!(status.is_success(), "Status: {}, Body: {}", status, body);
 }
+
+#[actix_web::test]
+async fn test_modrinth_tag_license() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::get()
+        .uri("/modrinth/v2/tag/license")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_modrinth_tag_license_text() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::get()
+        .uri("/modrinth/v2/tag/license/MIT")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_modrinth_tag_license_text_not_found() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::get()
+        .uri("/modrinth/v2/tag/license/NOT-A-LICENSE")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn test_modrinth_tag_donation_platform() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::get()
+        .uri("/modrinth/v2/tag/donation_platform")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+}
+
+#[actix_web::test]
+async fn test_modrinth_tag_project_type() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::get()
+        .uri("/modrinth/v2/tag/project_type")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+    assert_tag_names(&body_bytes);
+}
+
+#[actix_web::test]
+async fn test_modrinth_tag_side_type() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::get()
+        .uri("/modrinth/v2/tag/side_type")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+    assert_tag_names(&body_bytes);
+}
+
+#[actix_web::test]
+async fn test_modrinth_tag_report_type() {
+    let app = init_service(create_test_app().await).await;
+    let req = TestRequest::get()
+        .uri("/modrinth/v2/tag/report_type")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+    assert_tag_names(&body_bytes);
+}
+
+// 与其他 tag 接口一致，每一项都带有 sync_at
+fn assert_tag_names(body_bytes: &[u8]) {
+    let tags: Vec<serde_json::Value> = serde_json::from_slice(body_bytes).unwrap();
+    assert!(!tags.is_empty());
+    for tag in tags {
+        assert!(tag["name"].is_string(), "{}", tag);
+        assert!(tag["sync_at"].is_string(), "{}", tag);
+    }
+}
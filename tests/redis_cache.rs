@@ -0,0 +1,185 @@
+use actix_web::{
+    dev::Service,
+    test::{TestRequest, init_service},
+};
+
+use futures::future::join_all;
+use redis::AsyncCommands;
+use serde_json::json;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use mcim_rust_api::db::_redis::RedisPool;
+use mcim_rust_api::errors::ApiError;
+use mcim_rust_api::models::admin::entities::AdminRole;
+use mcim_rust_api::services::admin_token::AdminTokenService;
+use mcim_rust_api::test_utils::{create_test_app, init_test_db, init_test_redis};
+use mcim_rust_api::utils::redis_cache::{
+    CACHE_BYPASS_HEADER, CACHE_STATUS_HEADER, CacheTtl, cacheable_json, create_key,
+    create_request_key,
+};
+
+static MOD_ID: i32 = 594678;
+
+#[test]
+fn test_request_key_normalization() {
+    assert_eq!(
+        create_request_key("GET", "/modrinth//v2/project/sodium/", "b=2&a=1", b""),
+        create_request_key("GET", "/modrinth/v2/project/sodium", "a=1&b=2", b"")
+    );
+    assert_ne!(
+        create_request_key("GET", "/modrinth/v2/projects", "", b""),
+        create_request_key("POST", "/modrinth/v2/projects", "", b"")
+    );
+    assert_ne!(
+        create_request_key("POST", "/curseforge/v1/mods", "", b"{\"modIds\":[1]}"),
+        create_request_key("POST", "/curseforge/v1/mods", "", b"{\"modIds\":[2]}")
+    );
+    // 路由缓存与 cacheable_json 的 key 不会重叠
+    assert_ne!(
+        create_request_key("GET", "/modrinth/v2/search", "query=a", b""),
+        create_key(
+            "GET".to_string(),
+            "/modrinth/v2/search".to_string(),
+            "query=a".to_string()
+        )
+    );
+}
+
+#[actix_web::test]
+async fn test_route_cache_hit() {
+    let app = init_service(create_test_app().await).await;
+    let uri = format!("/curseforge/v1/mods/{}", MOD_ID);
+
+    let req = TestRequest::get().uri(&uri).to_request();
+    let resp = app.call(req).await.unwrap();
+    assert!(resp.status().is_success());
+
+    let req = TestRequest::get().uri(&uri).to_request();
+    let resp = app.call(req).await.unwrap();
+    assert!(resp.status().is_success());
+    assert_eq!(resp.headers().get(CACHE_STATUS_HEADER).unwrap(), "HIT");
+}
+
+#[actix_web::test]
+async fn test_route_cache_bypass() {
+    let app = init_service(create_test_app().await).await;
+    let uri = format!("/curseforge/v1/mods/{}", MOD_ID);
+    let tokens = AdminTokenService::new(init_test_db().await);
+    let name = format!("test-{}", rand::random::<u32>());
+    let (token, _) = tokens
+        .create_token(&name, AdminRole::Operator)
+        .await
+        .unwrap();
+
+    let req = TestRequest::get().uri(&uri).to_request();
+    app.call(req).await.unwrap();
+
+    // 匿名请求忽略 bypass 请求头
+    let req = TestRequest::get()
+        .uri(&uri)
+        .insert_header((CACHE_BYPASS_HEADER, "1"))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.headers().get(CACHE_STATUS_HEADER).unwrap(), "HIT");
+
+    let req = TestRequest::get()
+        .uri(&uri)
+        .insert_header((CACHE_BYPASS_HEADER, "1"))
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert!(resp.status().is_success());
+    assert_eq!(resp.headers().get(CACHE_STATUS_HEADER).unwrap(), "MISS");
+
+    tokens.revoke_token(&name).await.unwrap();
+}
+
+#[actix_web::test]
+async fn test_cacheable_json_single_flight() {
+    let pool = init_test_redis().await;
+    let key = create_key(
+        "GET".to_string(),
+        "/test/single-flight".to_string(),
+        format!("nonce={}", rand::random::<u64>()),
+    );
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let requests = (0..8).map(|_| {
+        let calls = calls.clone();
+        cacheable_json(
+            pool.clone(),
+            key.clone(),
+            CacheTtl::new(60, 120),
+            move || {
+                Box::pin(async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    actix_web::rt::time::sleep(Duration::from_millis(200)).await;
+                    Ok::<_, ApiError>(json!({ "value": 1 }))
+                })
+            },
+        )
+    });
+    let responses = join_all(requests).await;
+
+    assert!(responses.iter().all(|resp| resp.is_ok()));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[actix_web::test]
+async fn test_cacheable_json_serves_stale_on_error() {
+    let pool = init_test_redis().await;
+    let key = create_key(
+        "GET".to_string(),
+        "/test/stale".to_string(),
+        format!("nonce={}", rand::random::<u64>()),
+    );
+
+    // 写入一份已软过期的缓存
+    let mut conn = pool.get().unwrap();
+    let _: () = conn
+        .hset_multiple(&key, &[("body", "{\"value\":1}"), ("stored_at", "0")])
+        .await
+        .unwrap();
+    let _: () = conn.expire(&key, 60).await.unwrap();
+
+    let resp = cacheable_json(pool.clone(), key.clone(), CacheTtl::new(1, 60), || {
+        Box::pin(async {
+            Err::<serde_json::Value, _>(ApiError::InternalServerError("upstream down".to_string()))
+        })
+    })
+    .await
+    .unwrap();
+
+    assert!(resp.status().is_success());
+    assert_eq!(resp.headers().get(CACHE_STATUS_HEADER).unwrap(), "STALE");
+    assert!(resp.headers().contains_key("Warning"));
+}
+
+async fn unavailable_redis() -> RedisPool {
+    let client = redis::Client::open("redis://127.0.0.1:1").unwrap();
+    RedisPool::connect_with(client).await
+}
+
+#[actix_web::test]
+async fn test_cacheable_json_without_redis() {
+    let pool = unavailable_redis().await;
+    assert!(!pool.is_connected());
+
+    let resp = cacheable_json(
+        pool,
+        create_key(
+            "GET".to_string(),
+            "/test/no-redis".to_string(),
+            String::new(),
+        ),
+        CacheTtl::new(60, 120),
+        || Box::pin(async { Ok::<_, ApiError>(json!({ "value": 1 })) }),
+    )
+    .await
+    .unwrap();
+
+    assert!(resp.status().is_success());
+    assert_eq!(resp.headers().get(CACHE_STATUS_HEADER).unwrap(), "MISS");
+}
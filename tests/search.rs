@@ -0,0 +1,158 @@
+use actix_web::{
+    body::to_bytes,
+    dev::Service,
+    http::StatusCode,
+    test::{TestRequest, init_service},
+};
+
+use mcim_rust_api::models::search::requests::UnifiedSearchQuery;
+use mcim_rust_api::services::search::normalize_query;
+use mcim_rust_api::test_utils::create_test_app_with;
+use mcim_rust_api::utils::app::SearchMode;
+use mcim_rust_api::utils::redis_cache::CACHE_STATUS_HEADER;
+
+fn unified_query(query: &str, platforms: Option<&str>, loader: Option<&str>) -> UnifiedSearchQuery {
+    UnifiedSearchQuery {
+        query: Some(query.to_string()),
+        platforms: platforms.map(String::from),
+        game_version: None,
+        loader: loader.map(String::from),
+        offset: None,
+        limit: None,
+    }
+}
+
+#[test]
+fn test_normalize_query() {
+    // 平台顺序、大小写和默认值不影响缓存 key
+    assert_eq!(
+        normalize_query(&unified_query(
+            " Sodium ",
+            Some("curseforge,modrinth"),
+            Some("Fabric")
+        ))
+        .unwrap(),
+        normalize_query(&unified_query("sodium", None, Some("fabric"))).unwrap()
+    );
+    assert_ne!(
+        normalize_query(&unified_query("sodium", Some("modrinth"), None)).unwrap(),
+        normalize_query(&unified_query("sodium", None, None)).unwrap()
+    );
+    assert!(normalize_query(&unified_query("sodium", Some("unknown"), None)).is_err());
+}
+
+#[actix_web::test]
+async fn test_unified_search_local() {
+    let app = init_service(
+        create_test_app_with(|state| {
+            state.modrinth_search_mode = SearchMode::Local;
+            state.curseforge_search_mode = SearchMode::Local;
+        })
+        .await,
+    )
+    .await;
+
+    let req = TestRequest::get()
+        .uri("/search?offset=0&limit=20")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    let hits = json_body["hits"].as_array().unwrap();
+    assert!(
+        hits.iter().any(|hit| hit["platform"] == "modrinth"),
+        "Body: {}",
+        body
+    );
+    assert!(
+        hits.iter().any(|hit| hit["platform"] == "curseforge"),
+        "Body: {}",
+        body
+    );
+    assert!(
+        hits.windows(2)
+            .all(|w| w[0]["downloads"].as_i64() >= w[1]["downloads"].as_i64()),
+        "Body: {}",
+        body
+    );
+    assert!(json_body["errors"].as_array().unwrap().is_empty());
+}
+
+#[actix_web::test]
+async fn test_unified_search_single_platform() {
+    let app = init_service(
+        create_test_app_with(|state| state.modrinth_search_mode = SearchMode::Local).await,
+    )
+    .await;
+
+    let req = TestRequest::get()
+        .uri("/search?platforms=modrinth&limit=5")
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    let hits = json_body["hits"].as_array().unwrap();
+    assert!(hits.len() <= 5);
+    assert!(
+        hits.iter().all(|hit| hit["platform"] == "modrinth"),
+        "Body: {}",
+        body
+    );
+}
+
+#[actix_web::test]
+async fn test_unified_search_partial_failure() {
+    // Curseforge 不认识 rift，只有 Modrinth 的结果
+    let app = init_service(
+        create_test_app_with(|state| {
+            state.modrinth_search_mode = SearchMode::Local;
+            state.curseforge_search_mode = SearchMode::Local;
+        })
+        .await,
+    )
+    .await;
+
+    let req = TestRequest::get().uri("/search?loader=rift").to_request();
+    let resp = app.call(req).await.unwrap();
+
+    let status = resp.status();
+    let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body_bytes);
+    assert!(status.is_success(), "Status: {}, Body: {}", status, body);
+
+    let json_body: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    let errors = json_body["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1, "Body: {}", body);
+    assert_eq!(errors[0]["platform"], "curseforge");
+
+    // 部分失败的结果不写入缓存
+    let req = TestRequest::get().uri("/search?loader=rift").to_request();
+    let resp = app.call(req).await.unwrap();
+    assert!(resp.status().is_success());
+    assert_ne!(resp.headers().get(CACHE_STATUS_HEADER).unwrap(), "HIT");
+}
+
+#[actix_web::test]
+async fn test_unified_search_invalid_params() {
+    let app = init_service(create_test_app_with(|_| {}).await).await;
+
+    for uri in [
+        "/search?platforms=unknown",
+        "/search?offset=90&limit=20",
+        "/search?limit=0",
+    ] {
+        let req = TestRequest::get().uri(uri).to_request();
+        let resp = app.call(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "URI: {}", uri);
+    }
+}
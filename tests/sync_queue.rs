@@ -0,0 +1,76 @@
+use redis::AsyncCommands;
+
+use mcim_rust_api::db::_redis::RedisPool;
+use mcim_rust_api::db::sync_queue::{QUEUE_DROPPED, QueueKeys, enqueue, track_enqueue};
+use mcim_rust_api::test_utils::init_test_redis;
+
+#[actix_web::test]
+async fn test_enqueue_records_demand() {
+    let pool = init_test_redis().await;
+    let queue = format!("test_queue_{}", rand::random::<u64>());
+    let keys = QueueKeys::new(&queue);
+
+    enqueue(&pool, &queue, &[1, 2], "test:first").await;
+    enqueue(&pool, &queue, &[2], "test:second").await;
+
+    let mut conn = pool.get().unwrap();
+    let ranked: Vec<(i32, i64)> = conn.zrevrange_withscores(&keys.hits, 0, -1).await.unwrap();
+    assert_eq!(ranked, vec![(2, 2), (1, 1)]);
+
+    let source: String = conn.hget(&keys.source, 2).await.unwrap();
+    assert_eq!(source, "test:second");
+
+    let first_seen: Option<i64> = conn.zscore(&keys.first_seen, 1).await.unwrap();
+    let last_seen: Option<i64> = conn.zscore(&keys.last_seen, 1).await.unwrap();
+    assert!(first_seen.is_some() && first_seen <= last_seen);
+
+    // 默认同时写入旧版 set，供尚未迁移的 mcim-sync 读取
+    let legacy = keys.legacy.clone().unwrap();
+    let mut legacy_members: Vec<i32> = conn.smembers(&legacy).await.unwrap();
+    legacy_members.sort();
+    assert_eq!(legacy_members, vec![1, 2]);
+
+    let _: () = conn
+        .del(&[
+            keys.hits,
+            keys.first_seen,
+            keys.last_seen,
+            keys.source,
+            legacy,
+        ])
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_enqueue_dropped_without_redis() {
+    let client = redis::Client::open("redis://127.0.0.1:1").unwrap();
+    let pool = RedisPool::connect_with(client).await;
+    let dropped = QUEUE_DROPPED.with_label_values(&["test_queue"]);
+    let before = dropped.get();
+
+    enqueue(&pool, "test_queue", &[1, 2, 3], "test").await;
+
+    assert_eq!(dropped.get(), before + 3);
+}
+
+#[actix_web::test]
+async fn test_track_enqueue() {
+    let client = redis::Client::open("redis://127.0.0.1:1").unwrap();
+    let pool = RedisPool::connect_with(client).await;
+
+    let (_, enqueued) = track_enqueue(async {
+        enqueue(&pool, "test_queue", &[1], "test").await;
+    })
+    .await;
+    assert!(enqueued);
+
+    // 空列表不算写入
+    let (value, enqueued) = track_enqueue(async {
+        enqueue::<i32>(&pool, "test_queue", &[], "test").await;
+        1
+    })
+    .await;
+    assert_eq!(value, 1);
+    assert!(!enqueued);
+}
@@ -0,0 +1,138 @@
+use actix_web::{
+    App, HttpResponse,
+    dev::Service,
+    http::StatusCode,
+    middleware::from_fn,
+    test::{TestRequest, init_service},
+    web,
+};
+
+use mcim_rust_api::db::_redis::RedisPool;
+use mcim_rust_api::models::user_agent::entities::{UserAgentAction, UserAgentRule};
+use mcim_rust_api::test_utils::init_test_db;
+use mcim_rust_api::utils::app::build_app_state;
+use mcim_rust_api::utils::user_agent_filter::{
+    USER_AGENT_REJECTED, USER_AGENT_RULE_HITS, UserAgentDecision, UserAgentFilter,
+    UserAgentFilterMode, filter_user_agent,
+};
+
+fn rule(pattern: &str, action: UserAgentAction) -> UserAgentRule {
+    UserAgentRule {
+        pattern: pattern.to_string(),
+        action,
+        description: None,
+    }
+}
+
+fn launcher_rules() -> Vec<UserAgentRule> {
+    vec![
+        rule("HMCL/*", UserAgentAction::Allow),
+        rule("PCL2/*", UserAgentAction::Allow),
+        rule("*bot*", UserAgentAction::Deny),
+    ]
+}
+
+async fn filtered_app(
+    filter: UserAgentFilter,
+) -> App<
+    impl actix_web::dev::ServiceFactory<
+        actix_web::dev::ServiceRequest,
+        Config = (),
+        Response = actix_web::dev::ServiceResponse<impl actix_web::body::MessageBody>,
+        Error = actix_web::Error,
+        InitError = (),
+    >,
+> {
+    let redis = RedisPool::connect_with(redis::Client::open("redis://127.0.0.1:1").unwrap()).await;
+    let mut state = build_app_state(init_test_db().await, redis);
+    state.user_agent_filter = filter;
+
+    App::new()
+        .app_data(web::Data::new(state))
+        .wrap(from_fn(filter_user_agent))
+        .route("/", web::get().to(HttpResponse::Ok))
+}
+
+#[test]
+fn test_rule_matching() {
+    let filter = UserAgentFilter::new(UserAgentFilterMode::Enforce, launcher_rules());
+
+    assert_eq!(
+        filter.check("hmcl/3.6.12"),
+        UserAgentDecision::Allowed {
+            pattern: Some("HMCL/*".to_string())
+        }
+    );
+    assert_eq!(
+        filter.check("HMCL/3.6.12 (crawler bot)"),
+        UserAgentDecision::Denied {
+            pattern: "*bot*".to_string()
+        }
+    );
+    assert_eq!(filter.check("curl/8.0"), UserAgentDecision::Unlisted);
+    assert_eq!(filter.check(""), UserAgentDecision::Unlisted);
+}
+
+#[test]
+fn test_denylist_only() {
+    let filter = UserAgentFilter::new(
+        UserAgentFilterMode::Enforce,
+        vec![rule("python-requests/*", UserAgentAction::Deny)],
+    );
+
+    assert_eq!(
+        filter.check("curl/8.0"),
+        UserAgentDecision::Allowed { pattern: None }
+    );
+    assert!(matches!(
+        filter.check("python-requests/2.31"),
+        UserAgentDecision::Denied { .. }
+    ));
+
+    // 重新加载后规则立即生效
+    filter.set_rules(Vec::new());
+    assert_eq!(
+        filter.check("python-requests/2.31"),
+        UserAgentDecision::Allowed { pattern: None }
+    );
+}
+
+#[actix_web::test]
+async fn test_enforce_rejects_unlisted() {
+    let mut filter = UserAgentFilter::new(UserAgentFilterMode::Enforce, launcher_rules());
+    filter.reject_status = StatusCode::IM_A_TEAPOT;
+    let app = init_service(filtered_app(filter).await).await;
+    let hits = USER_AGENT_RULE_HITS.with_label_values(&["PCL2/*", "allow"]);
+    let before = hits.get();
+
+    let req = TestRequest::get()
+        .uri("/")
+        .insert_header(("User-Agent", "PCL2/2.8.0"))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(hits.get(), before + 1);
+
+    let req = TestRequest::get()
+        .uri("/")
+        .insert_header(("User-Agent", "curl/8.0"))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::IM_A_TEAPOT);
+}
+
+#[actix_web::test]
+async fn test_monitor_only_counts() {
+    let filter = UserAgentFilter::new(UserAgentFilterMode::Monitor, launcher_rules());
+    let app = init_service(filtered_app(filter).await).await;
+    let rejected = USER_AGENT_REJECTED.with_label_values(&["monitor", "unlisted"]);
+    let before = rejected.get();
+
+    let req = TestRequest::get()
+        .uri("/")
+        .insert_header(("User-Agent", "curl/8.0"))
+        .to_request();
+    let resp = app.call(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(rejected.get(), before + 1);
+}